@@ -24,8 +24,7 @@ async fn main() {
 
 async fn general(use_testnet: bool) {
     let mut general: General = if use_testnet {
-        let config = Config::default().set_rest_api_endpoint("https://testnet.binance.vision");
-        General::new_with_config(None, None, &config).unwrap()
+        General::new_with_config(None, None, &Config::spot_testnet()).unwrap()
     } else {
         General::new(None, None).unwrap()
     };
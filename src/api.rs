@@ -1,8 +1,13 @@
+/// Rest api routes.
+///
+/// `Futures` routes resolve to the USD-M (`fapi`) paths, `Delivery` routes to
+/// the COIN-M (`dapi`) paths of the same endpoints.
 #[derive(Clone)]
 pub enum API {
     Spot(Spot),
     Savings(Sapi),
     Futures(Futures),
+    Delivery(Futures),
 }
 
 /// Endpoint for production and test orders.
@@ -20,10 +25,12 @@ pub enum Spot {
     Klines,
     AvgPrice,
     Ticker24hr,
+    TickerWindow,
     Price,
     BookTicker,
     Order,
     OrderTest,
+    CancelReplace,
     OpenOrders,
     AllOrders,
     Oco,
@@ -33,6 +40,7 @@ pub enum Spot {
     Account,
     AccountCommission,
     MyTrades,
+    MyAllocations,
     UserDataStream,
 }
 
@@ -42,6 +50,12 @@ pub enum Sapi {
     AssetDetail,
     DepositAddress,
     SpotFuturesTransfer,
+    DustBtc,
+    DustTransfer,
+    Withdraw,
+    DepositHistory,
+    WithdrawHistory,
+    UniversalTransfer,
 }
 
 #[derive(Clone)]
@@ -59,6 +73,7 @@ pub enum Futures {
     MarkPriceKlines,
     PremiumIndex,
     FundingRate,
+    FundingInfo,
     Ticker24hr,
     TickerPrice,
     BookTicker,
@@ -79,10 +94,13 @@ pub enum Futures {
     LvtKlines,
     IndexInfo,
     ChangeInitialLeverage,
+    MarginType,
     Account,
     OpenOrders,
     UserDataStream,
     Income,
+    PositionMargin,
+    PositionMarginHistory,
     HistoricalDataDownloadId,
     HistoricalDataDownloadLink,
     DownloadLink(String),
@@ -102,10 +120,12 @@ impl From<API> for String {
                 Spot::Klines => "/api/v3/klines".to_owned(),
                 Spot::AvgPrice => "/api/v3/avgPrice".to_owned(),
                 Spot::Ticker24hr => "/api/v3/ticker/24hr".to_owned(),
+                Spot::TickerWindow => "/api/v3/ticker".to_owned(),
                 Spot::Price => "/api/v3/ticker/price".to_owned(),
                 Spot::BookTicker => "/api/v3/ticker/bookTicker".to_owned(),
                 Spot::Order => "/api/v3/order".to_owned(),
                 Spot::OrderTest => "/api/v3/order/test".to_owned(),
+                Spot::CancelReplace => "/api/v3/order/cancelReplace".to_owned(),
                 Spot::OpenOrders => "/api/v3/openOrders".to_owned(),
                 Spot::AllOrders => "/api/v3/allOrders".to_owned(),
                 Spot::Oco => "/api/v3/order/oco".to_owned(),
@@ -115,6 +135,7 @@ impl From<API> for String {
                 Spot::Account => "/api/v3/account".to_owned(),
                 Spot::AccountCommission => "/api/v3/account/commission".to_owned(),
                 Spot::MyTrades => "/api/v3/myTrades".to_owned(),
+                Spot::MyAllocations => "/api/v3/myAllocations".to_owned(),
                 Spot::UserDataStream => "/api/v3/userDataStream".to_owned(),
             },
             API::Savings(route) => match route {
@@ -122,6 +143,12 @@ impl From<API> for String {
                 Sapi::AssetDetail => "/sapi/v1/asset/assetDetail".to_owned(),
                 Sapi::DepositAddress => "/sapi/v1/capital/deposit/address".to_owned(),
                 Sapi::SpotFuturesTransfer => "/sapi/v1/futures/transfer".to_owned(),
+                Sapi::DustBtc => "/sapi/v1/asset/dust-btc".to_owned(),
+                Sapi::DustTransfer => "/sapi/v1/asset/dust".to_owned(),
+                Sapi::Withdraw => "/sapi/v1/capital/withdraw/apply".to_owned(),
+                Sapi::DepositHistory => "/sapi/v1/capital/deposit/hisrec".to_owned(),
+                Sapi::WithdrawHistory => "/sapi/v1/capital/withdraw/history".to_owned(),
+                Sapi::UniversalTransfer => "/sapi/v1/asset/transfer".to_owned(),
             },
             API::Futures(route) => match route {
                 Futures::Ping => "/fapi/v1/ping".to_owned(),
@@ -137,6 +164,7 @@ impl From<API> for String {
                 Futures::MarkPriceKlines => "/fapi/v1/markPriceKlines".to_owned(),
                 Futures::PremiumIndex => "/fapi/v1/premiumIndex".to_owned(),
                 Futures::FundingRate => "/fapi/v1/fundingRate".to_owned(),
+                Futures::FundingInfo => "/fapi/v1/fundingInfo".to_owned(),
                 Futures::Ticker24hr => "/fapi/v1/ticker/24hr".to_owned(),
                 Futures::TickerPrice => "/fapi/v1/ticker/price".to_owned(),
                 Futures::BookTicker => "/fapi/v1/ticker/bookTicker".to_owned(),
@@ -163,10 +191,67 @@ impl From<API> for String {
                 Futures::LvtKlines => "/fapi/v1/lvtKlines".to_owned(),
                 Futures::IndexInfo => "/fapi/v1/indexInfo".to_owned(),
                 Futures::ChangeInitialLeverage => "/fapi/v1/leverage".to_owned(),
+                Futures::MarginType => "/fapi/v1/marginType".to_owned(),
                 Futures::Account => "/fapi/v2/account".to_owned(),
                 Futures::OpenOrders => "/fapi/v1/openOrders".to_owned(),
                 Futures::UserDataStream => "/fapi/v1/listenKey".to_owned(),
                 Futures::Income => "/fapi/v1/income".to_owned(),
+                Futures::PositionMargin => "/fapi/v1/positionMargin".to_owned(),
+                Futures::PositionMarginHistory => "/fapi/v1/positionMargin/history".to_owned(),
+                Futures::HistoricalDataDownloadId => "/sapi/v1/futuresHistDataId".to_owned(),
+                Futures::HistoricalDataDownloadLink => "/sapi/v1/downloadLink".to_owned(),
+                Futures::DownloadLink(url) => url,
+            },
+            API::Delivery(route) => match route {
+                Futures::Ping => "/dapi/v1/ping".to_owned(),
+                Futures::Time => "/dapi/v1/time".to_owned(),
+                Futures::ExchangeInfo => "/dapi/v1/exchangeInfo".to_owned(),
+                Futures::Depth => "/dapi/v1/depth".to_owned(),
+                Futures::Trades => "/dapi/v1/trades".to_owned(),
+                Futures::HistoricalTrades => "/dapi/v1/historicalTrades".to_owned(),
+                Futures::AggTrades => "/dapi/v1/aggTrades".to_owned(),
+                Futures::Klines => "/dapi/v1/klines".to_owned(),
+                Futures::ContinuousKlines => "/dapi/v1/continuousKlines".to_owned(),
+                Futures::IndexPriceKlines => "/dapi/v1/indexPriceKlines".to_owned(),
+                Futures::MarkPriceKlines => "/dapi/v1/markPriceKlines".to_owned(),
+                Futures::PremiumIndex => "/dapi/v1/premiumIndex".to_owned(),
+                Futures::FundingRate => "/dapi/v1/fundingRate".to_owned(),
+                Futures::FundingInfo => "/dapi/v1/fundingInfo".to_owned(),
+                Futures::Ticker24hr => "/dapi/v1/ticker/24hr".to_owned(),
+                Futures::TickerPrice => "/dapi/v1/ticker/price".to_owned(),
+                Futures::BookTicker => "/dapi/v1/ticker/bookTicker".to_owned(),
+                Futures::AllForceOrders => "/dapi/v1/allForceOrders".to_owned(),
+                Futures::AllOpenOrders => "/dapi/v1/allOpenOrders".to_owned(),
+                Futures::AllOrders => "/dapi/v1/allOrders".to_owned(),
+                Futures::UserTrades => "/dapi/v1/userTrades".to_owned(),
+                Futures::PositionSide => "/dapi/v1/positionSide/dual".to_owned(),
+                Futures::Order => "/dapi/v1/order".to_owned(),
+                Futures::PositionRisk => "/dapi/v1/positionRisk".to_owned(),
+                Futures::Balance => "/dapi/v1/balance".to_owned(),
+                Futures::OpenInterest => "/dapi/v1/openInterest".to_owned(),
+                Futures::OpenInterestHist => "/futures/data/openInterestHist".to_owned(),
+                Futures::TopLongShortAccountRatio => {
+                    "/futures/data/topLongShortAccountRatio".to_owned()
+                }
+                Futures::TopLongShortPositionRatio => {
+                    "/futures/data/topLongShortPositionRatio".to_owned()
+                }
+                Futures::GlobalLongShortAccountRatio => {
+                    "/futures/data/globalLongShortAccountRatio".to_owned()
+                }
+                Futures::TakerlongshortRatio => "/futures/data/takerBuySellVol".to_owned(),
+                // Leveraged tokens and composite indexes are USD-M only, COIN-M
+                // markets reject them before a request is sent.
+                Futures::LvtKlines => "/fapi/v1/lvtKlines".to_owned(),
+                Futures::IndexInfo => "/fapi/v1/indexInfo".to_owned(),
+                Futures::ChangeInitialLeverage => "/dapi/v1/leverage".to_owned(),
+                Futures::MarginType => "/dapi/v1/marginType".to_owned(),
+                Futures::Account => "/dapi/v1/account".to_owned(),
+                Futures::OpenOrders => "/dapi/v1/openOrders".to_owned(),
+                Futures::UserDataStream => "/dapi/v1/listenKey".to_owned(),
+                Futures::Income => "/dapi/v1/income".to_owned(),
+                Futures::PositionMargin => "/dapi/v1/positionMargin".to_owned(),
+                Futures::PositionMarginHistory => "/dapi/v1/positionMargin/history".to_owned(),
                 Futures::HistoricalDataDownloadId => "/sapi/v1/futuresHistDataId".to_owned(),
                 Futures::HistoricalDataDownloadLink => "/sapi/v1/downloadLink".to_owned(),
                 Futures::DownloadLink(url) => url,
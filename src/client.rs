@@ -1,3 +1,12 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
 use bytes::Bytes;
 use error_chain::bail;
 use hex::encode as hex_encode;
@@ -7,59 +16,233 @@ use reqwest::header::HeaderMap;
 use reqwest::header::HeaderName;
 use reqwest::header::HeaderValue;
 use reqwest::header::CONTENT_TYPE;
+use reqwest::header::RETRY_AFTER;
 use reqwest::header::USER_AGENT;
+use reqwest::RequestBuilder;
 use reqwest::Response;
 use reqwest::StatusCode;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::SignatureEncoding;
+use rsa::signature::Signer;
 use serde::de::DeserializeOwned;
 use sha2::Sha256;
+use tokio::time::sleep;
 use tracing::debug;
+use url::form_urlencoded::byte_serialize;
 
 use crate::api::API;
+use crate::config::Config;
+use crate::config::RateLimitRetry;
+use crate::config::ServerErrorRetry;
+use crate::config::SignatureKind;
 use crate::errors::BinanceContentError;
 use crate::errors::ErrorKind;
 use crate::errors::Result;
+use crate::model::RateLimitInterval;
+use crate::model::RateLimitType;
+use crate::util;
+use crate::util::TimeOffset;
+
+/// Key signing the requests, as set by the `SignatureKind` of the
+/// configuration.
+#[derive(Clone, Debug)]
+enum RequestSigner {
+    Hmac(String),
+    Ed25519(ed25519_dalek::SigningKey),
+    Rsa(rsa::pkcs1v15::SigningKey<rsa::sha2::Sha256>),
+}
+
+impl RequestSigner {
+    /// Parse the secret key, a PEM private key for the asymmetric kinds.
+    fn new(secret_key: Option<String>, kind: &SignatureKind) -> Result<Self> {
+        let Some(secret_key) = secret_key else {
+            return Ok(Self::Hmac(String::new()));
+        };
+        match kind {
+            SignatureKind::Hmac => Ok(Self::Hmac(secret_key)),
+            SignatureKind::Ed25519 => {
+                let key = ed25519_dalek::SigningKey::from_pkcs8_pem(&secret_key)
+                    .map_err(|e| ErrorKind::InvalidSigningKey(e.to_string()))?;
+                Ok(Self::Ed25519(key))
+            }
+            SignatureKind::Rsa => {
+                let key = rsa::RsaPrivateKey::from_pkcs8_pem(&secret_key)
+                    .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(&secret_key))
+                    .map_err(|e| ErrorKind::InvalidSigningKey(e.to_string()))?;
+                Ok(Self::Rsa(rsa::pkcs1v15::SigningKey::new(key)))
+            }
+        }
+    }
+
+    /// Signature of `payload`, encoded for a query string: hex for HMAC,
+    /// percent-encoded base64 otherwise.
+    fn sign(&self, payload: &str) -> String {
+        let signature = match self {
+            Self::Hmac(secret_key) => {
+                let mut signed_key = Hmac::<Sha256>::new_from_slice(secret_key.as_bytes()).unwrap();
+                signed_key.update(payload.as_bytes());
+                return hex_encode(signed_key.finalize().into_bytes());
+            }
+            Self::Ed25519(key) => BASE64.encode(key.sign(payload.as_bytes()).to_bytes()),
+            Self::Rsa(key) => BASE64.encode(key.sign(payload.as_bytes()).to_vec()),
+        };
+        byte_serialize(signature.as_bytes()).collect()
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Client {
     api_key: String,
-    secret_key: String,
+    signer: RequestSigner,
     host: String,
     inner: reqwest::Client,
+    rate_limits: Arc<Mutex<BTreeMap<(RateLimitInterval, RateLimitType), u64>>>,
+    rate_limit_retry: Option<RateLimitRetry>,
+    server_error_retry: Option<ServerErrorRetry>,
+    post_params_in_body: bool,
+    time_offset: TimeOffset,
 }
 
 impl Client {
-    pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String) -> Result<Self> {
+    pub fn new(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        host: String,
+        config: &Config,
+    ) -> Result<Self> {
         Ok(Client {
             api_key: api_key.unwrap_or_default(),
-            secret_key: secret_key.unwrap_or_default(),
+            signer: RequestSigner::new(secret_key, &config.signature_kind)?,
             host,
-            inner: reqwest::Client::builder().pool_idle_timeout(None).build()?,
+            inner: match &config.http_client {
+                Some(http_client) => http_client.clone(),
+                None => reqwest::Client::builder()
+                    .pool_idle_timeout(None)
+                    .timeout(config.timeout)
+                    .connect_timeout(config.timeout)
+                    .build()?,
+            },
+            rate_limits: Arc::new(Mutex::new(BTreeMap::new())),
+            rate_limit_retry: config.rate_limit_retry,
+            server_error_retry: config.server_error_retry,
+            post_params_in_body: config.post_params_in_body,
+            time_offset: config.time_offset.clone(),
         })
     }
 
+    /// A client sharing the credentials, connection pool and rate limit
+    /// usage of this one, sending its requests to `host`.
+    pub(crate) fn with_host(&self, host: String) -> Self {
+        Client {
+            api_key: self.api_key.clone(),
+            signer: self.signer.clone(),
+            host,
+            inner: self.inner.clone(),
+            rate_limits: Arc::clone(&self.rate_limits),
+            rate_limit_retry: self.rate_limit_retry,
+            server_error_retry: self.server_error_retry,
+            post_params_in_body: self.post_params_in_body,
+            time_offset: self.time_offset.clone(),
+        }
+    }
+
+    /// Offset of the server clock over the local clock, shared with the
+    /// clients built from the same `Config`.
+    #[must_use]
+    pub fn time_offset(&self) -> &TimeOffset {
+        &self.time_offset
+    }
+
+    /// Latest usage reported by the rate limit headers of the responses,
+    /// per interval and kind of limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    #[must_use]
+    pub fn rate_limit_usage(&self) -> BTreeMap<(RateLimitInterval, RateLimitType), u64> {
+        self.rate_limits.lock().unwrap().clone()
+    }
+
+    /// Request weight used over the current minute, from the latest
+    /// `x-mbx-used-weight-1m` header, to pace requests against the
+    /// `REQUEST_WEIGHT` limit of the exchange information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    #[must_use]
+    pub fn used_weight(&self) -> Option<u64> {
+        self.rate_limit_used(RateLimitInterval::Minute(1), RateLimitType::RequestWeight)
+    }
+
+    /// Orders placed over `interval`, from the latest
+    /// `x-mbx-order-count-<interval>` header of the order endpoints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lock is poisoned.
+    #[must_use]
+    pub fn order_count(&self, interval: RateLimitInterval) -> Option<u64> {
+        self.rate_limit_used(interval, RateLimitType::Orders)
+    }
+
+    fn rate_limit_used(
+        &self,
+        interval: RateLimitInterval,
+        rate_limit_type: RateLimitType,
+    ) -> Option<u64> {
+        self.rate_limits
+            .lock()
+            .unwrap()
+            .get(&(interval, rate_limit_type))
+            .copied()
+    }
+
+    /// Build a signed request, timestamped with the local clock corrected
+    /// by the time offset of the client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `recv_window` exceeds the maximum accepted by
+    /// Binance or if the timestamp cannot be generated.
+    pub fn build_signed_request(
+        &self,
+        parameters: BTreeMap<String, String>,
+        recv_window: u64,
+    ) -> Result<String> {
+        let start = util::offset_now(self.time_offset.get());
+        util::build_signed_request_custom(parameters, recv_window, start)
+    }
+
     pub async fn get_signed<T: DeserializeOwned>(
         &self,
         endpoint: API,
         request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
+        let url = self.endpoint_url(endpoint);
+        let headers = self.build_headers(true)?;
         let client = &self.inner;
-        let response = client
-            .get(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()
+        let response = self
+            .send_get(|elapsed| {
+                let url = self.sign_request(&url, request.as_deref(), elapsed);
+                client.get(url).headers(headers.clone())
+            })
             .await?;
 
         self.handler(response).await
     }
 
     pub async fn get_signed_bytes(&self, endpoint: API, request: Option<String>) -> Result<Bytes> {
-        let url = self.sign_request(endpoint, request);
+        let url = self.endpoint_url(endpoint);
+        let headers = self.build_headers(true)?;
         let client = &self.inner;
-        let response = client
-            .get(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()
+        let response = self
+            .send_get(|elapsed| {
+                let url = self.sign_request(&url, request.as_deref(), elapsed);
+                client.get(url).headers(headers.clone())
+            })
             .await?;
 
         self.bytes_handler(response).await
@@ -70,12 +253,47 @@ impl Client {
         endpoint: API,
         request: String,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, Some(request));
+        self.send_post_signed(endpoint, request, None).await
+    }
+
+    /// Send a signed POST request that fails once `timeout` elapses.
+    ///
+    /// The timeout only bounds this request. A request that timed out may
+    /// still have been executed by the server.
+    pub async fn post_signed_with_timeout<T: DeserializeOwned>(
+        &self,
+        endpoint: API,
+        request: String,
+        timeout: Duration,
+    ) -> Result<T> {
+        self.send_post_signed(endpoint, request, Some(timeout))
+            .await
+    }
+
+    async fn send_post_signed<T: DeserializeOwned>(
+        &self,
+        endpoint: API,
+        request: String,
+        timeout: Option<Duration>,
+    ) -> Result<T> {
+        let url = self.endpoint_url(endpoint);
+        let headers = self.build_headers(true)?;
         let client = &self.inner;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()
+        let response = self
+            .send(|elapsed| {
+                let mut builder = if self.post_params_in_body {
+                    client
+                        .post(url.as_str())
+                        .body(self.signed_params(Some(&request), elapsed))
+                } else {
+                    client.post(self.sign_request(&url, Some(&request), elapsed))
+                };
+                builder = builder.headers(headers.clone());
+                if let Some(timeout) = timeout {
+                    builder = builder.timeout(timeout);
+                }
+                builder
+            })
             .await?;
 
         self.handler(response).await
@@ -86,12 +304,14 @@ impl Client {
         endpoint: API,
         request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
+        let url = self.endpoint_url(endpoint);
+        let headers = self.build_headers(true)?;
         let client = &self.inner;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()
+        let response = self
+            .send(|elapsed| {
+                let url = self.sign_request(&url, request.as_deref(), elapsed);
+                client.delete(url).headers(headers.clone())
+            })
             .await?;
 
         self.handler(response).await
@@ -110,7 +330,30 @@ impl Client {
         }
 
         let client = &self.inner;
-        let response = client.get(url.as_str()).send().await?;
+        let response = self.send_get(|_| client.get(url.as_str())).await?;
+
+        self.handler(response).await
+    }
+
+    /// Send an unsigned GET request that carries the API key, as required by
+    /// market data endpoints such as historical trades.
+    pub async fn get_with_api_key<T: DeserializeOwned>(
+        &self,
+        endpoint: API,
+        request: Option<String>,
+    ) -> Result<T> {
+        let mut url: String = format!("{}{}", self.host, String::from(endpoint));
+        if let Some(request) = request {
+            if !request.is_empty() {
+                url.push_str(format!("?{request}").as_str());
+            }
+        }
+
+        let headers = self.build_headers(false)?;
+        let client = &self.inner;
+        let response = self
+            .send_get(|_| client.get(url.as_str()).headers(headers.clone()))
+            .await?;
 
         self.handler(response).await
     }
@@ -118,11 +361,10 @@ impl Client {
     pub async fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
         let url: String = format!("{}{}", self.host, String::from(endpoint));
 
+        let headers = self.build_headers(false)?;
         let client = &self.inner;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(false)?)
-            .send()
+        let response = self
+            .send(|_| client.post(url.as_str()).headers(headers.clone()))
             .await?;
 
         self.handler(response).await
@@ -132,12 +374,15 @@ impl Client {
         let url: String = format!("{}{}", self.host, String::from(endpoint));
         let data: String = format!("listenKey={listen_key}");
 
+        let headers = self.build_headers(false)?;
         let client = &self.inner;
-        let response = client
-            .put(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()
+        let response = self
+            .send(|_| {
+                client
+                    .put(url.as_str())
+                    .headers(headers.clone())
+                    .body(data.clone())
+            })
             .await?;
 
         self.handler(response).await
@@ -147,37 +392,120 @@ impl Client {
         let url: String = format!("{}{}", self.host, String::from(endpoint));
         let data: String = format!("listenKey={listen_key}");
 
+        let headers = self.build_headers(false)?;
         let client = &self.inner;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()
+        let response = self
+            .send(|_| {
+                client
+                    .delete(url.as_str())
+                    .headers(headers.clone())
+                    .body(data.clone())
+            })
             .await?;
 
         self.handler(response).await
     }
 
+    /// Send the request built by `build`, sending it again as long as the
+    /// rate limit retry allows.
+    ///
+    /// `build` is given the time elapsed since the first attempt, by which
+    /// the timestamp of a signed request is moved forward.
+    async fn send<F>(&self, build: F) -> Result<Response>
+    where
+        F: Fn(Duration) -> RequestBuilder,
+    {
+        let start = Instant::now();
+        let mut retries = 0;
+        loop {
+            let response = build(start.elapsed()).send().await?;
+            let Some(delay) = self.retry_delay(&response, retries) else {
+                return Ok(response);
+            };
+            self.record_rate_limits(response.headers());
+            retries += 1;
+            debug!(
+                "Rate limited ({}), retry {} in {:?}",
+                response.status(),
+                retries,
+                delay
+            );
+            sleep(delay).await;
+        }
+    }
+
+    /// Send the GET request built by `build`, sending it again on server
+    /// errors as long as the server error retry allows.
+    async fn send_get<F>(&self, build: F) -> Result<Response>
+    where
+        F: Fn(Duration) -> RequestBuilder,
+    {
+        let Some(retry) = self.server_error_retry else {
+            return self.send(build).await;
+        };
+        let start = Instant::now();
+        let mut retries = 0;
+        loop {
+            let result = self.send(|_| build(start.elapsed())).await;
+            let server_error = match &result {
+                Ok(response) => matches!(
+                    response.status(),
+                    StatusCode::INTERNAL_SERVER_ERROR
+                        | StatusCode::BAD_GATEWAY
+                        | StatusCode::SERVICE_UNAVAILABLE
+                ),
+                Err(e) => {
+                    matches!(e.kind(), ErrorKind::ReqError(e) if e.is_connect() || e.is_request())
+                }
+            };
+            if !server_error || retries >= retry.max_retries {
+                return result;
+            }
+            retries += 1;
+            let delay = retry.delay(retries);
+            debug!("Server error, retry {} in {:?}", retries, delay);
+            sleep(delay).await;
+        }
+    }
+
+    fn retry_delay(&self, response: &Response, retries: u32) -> Option<Duration> {
+        let retry = self.rate_limit_retry?;
+        if !matches!(
+            response.status(),
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::IM_A_TEAPOT
+        ) || retries >= retry.max_retries
+        {
+            return None;
+        }
+        let delay = retry_after(response.headers()).unwrap_or(retry.default_delay);
+        (delay <= retry.max_delay).then_some(delay)
+    }
+
     // Request must be signed
-    fn sign_request(&self, endpoint: API, request: Option<String>) -> String {
+    fn sign_request(&self, url: &str, request: Option<&str>, elapsed: Duration) -> String {
+        format!("{}?{}", url, self.signed_params(request, elapsed))
+    }
+
+    fn endpoint_url(&self, endpoint: API) -> String {
+        let endpoint = String::from(endpoint);
         // If endpoint starts with http, then it is a full url, no need to add host.
-        let host = if String::from(endpoint.clone()).starts_with("http") {
-            String::new()
+        if endpoint.starts_with("http") {
+            endpoint
         } else {
-            self.host.clone()
-        };
+            format!("{}{}", self.host, endpoint)
+        }
+    }
+
+    /// The parameters followed by their signature, their timestamp moved
+    /// forward by `elapsed`.
+    fn signed_params(&self, request: Option<&str>, elapsed: Duration) -> String {
         if let Some(request) = request {
-            let mut signed_key =
-                Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            signed_key.update(request.as_bytes());
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("{request}&signature={signature}");
-            format!("{}{}?{}", host, String::from(endpoint), request_body)
+            let request = shift_timestamp(request, elapsed);
+            let signature = self.signer.sign(&request);
+            format!("{request}&signature={signature}")
         } else {
-            let signed_key = Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("&signature={signature}");
-            format!("{}{}?{}", host, String::from(endpoint), request_body)
+            let signature = self.signer.sign("");
+            format!("&signature={signature}")
         }
     }
 
@@ -199,75 +527,98 @@ impl Client {
         Ok(custom_headers)
     }
 
-    async fn bytes_handler(&self, response: Response) -> Result<Bytes> {
-        if response.headers().contains_key("x-mbx-used-weight-1m") {
-            let used_weights = response.headers().get("x-mbx-used-weight-1m").unwrap();
-            debug!("Used weights: {}", used_weights.to_str().unwrap());
+    fn record_rate_limits(&self, headers: &HeaderMap) {
+        let mut rate_limits = self.rate_limits.lock().unwrap();
+        for (name, value) in headers {
+            let name = name.as_str();
+            let (rate_limit_type, suffix) =
+                if let Some(suffix) = name.strip_prefix("x-mbx-used-weight-") {
+                    (RateLimitType::RequestWeight, suffix)
+                } else if let Some(suffix) = name.strip_prefix("x-mbx-order-count-") {
+                    (RateLimitType::Orders, suffix)
+                } else {
+                    continue;
+                };
+            let Some(interval) = RateLimitInterval::from_suffix(suffix) else {
+                continue;
+            };
+            let Some(used) = value.to_str().ok().and_then(|v| v.parse().ok()) else {
+                continue;
+            };
+            debug!("Rate limit {}: {}", name, used);
+            rate_limits.insert((interval, rate_limit_type), used);
         }
+    }
 
-        if response.status() == StatusCode::TOO_MANY_REQUESTS {
-            bail!(ErrorKind::TooManyRequest)
-        }
-        assert!(
-            response.status() != StatusCode::IM_A_TEAPOT,
-            "We were told we are a teapot"
-        );
-
-        match response.status() {
-            StatusCode::OK => Ok(response.bytes().await?),
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                bail!("Internal Server Error");
-            }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                bail!("Service Unavailable");
-            }
-            StatusCode::UNAUTHORIZED => {
-                bail!("Unauthorized");
-            }
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
+    async fn bytes_handler(&self, response: Response) -> Result<Bytes> {
+        self.record_rate_limits(response.headers());
+        let response = error_for_status(response).await?;
 
-                Err(ErrorKind::BinanceError(error).into())
-            }
-            s => {
-                bail!(format!("Received response: {:#?}", s));
-            }
-        }
+        Ok(response.bytes().await?)
     }
 
     async fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        if response.headers().contains_key("x-mbx-used-weight-1m") {
-            let used_weights = response.headers().get("x-mbx-used-weight-1m").unwrap();
-            debug!("Used weights: {}", used_weights.to_str().unwrap());
-        }
+        self.record_rate_limits(response.headers());
+        let response = error_for_status(response).await?;
 
-        if response.status() == StatusCode::TOO_MANY_REQUESTS {
-            bail!(ErrorKind::TooManyRequest)
-        }
-        assert!(
-            response.status() != StatusCode::IM_A_TEAPOT,
-            "We were told we are a teapot"
-        );
+        Ok(response.json::<T>().await?)
+    }
+}
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<T>().await?),
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                bail!("Internal Server Error");
-            }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                bail!("Service Unavailable");
-            }
-            StatusCode::UNAUTHORIZED => {
-                bail!("Unauthorized");
-            }
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
+/// Return the response if it succeeded, or its error otherwise.
+///
+/// Error bodies are parsed into a `BinanceContentError` whatever their
+/// status, the status alone being reported for the bodies that are not one.
+async fn error_for_status(response: Response) -> Result<Response> {
+    match response.status() {
+        StatusCode::OK => return Ok(response),
+        StatusCode::TOO_MANY_REQUESTS => bail!(ErrorKind::TooManyRequest),
+        StatusCode::IM_A_TEAPOT => bail!(ErrorKind::IpBanned(retry_after(response.headers()))),
+        _ => {}
+    }
 
-                Err(ErrorKind::BinanceError(error).into())
-            }
-            s => {
-                bail!(format!("Received response: {:?}", s));
-            }
-        }
+    let status = response.status();
+    let body = response.text().await?;
+    // Binance blocks restricted regions with a 451, or an HTML 403.
+    if status == StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS {
+        bail!(ErrorKind::GeoRestricted(status.as_u16(), body))
+    }
+    if let Ok(error) = serde_json::from_str::<BinanceContentError>(&body) {
+        bail!(ErrorKind::BinanceError(error))
+    }
+    match status {
+        StatusCode::FORBIDDEN => bail!(ErrorKind::GeoRestricted(status.as_u16(), body)),
+        StatusCode::INTERNAL_SERVER_ERROR => bail!("Internal Server Error"),
+        StatusCode::SERVICE_UNAVAILABLE => bail!("Service Unavailable"),
+        StatusCode::UNAUTHORIZED => bail!("Unauthorized"),
+        s => bail!(format!("Received response: {:?}", s)),
     }
 }
+
+/// Delay of a `Retry-After` header, in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers.get(RETRY_AFTER)?.to_str().ok()?.parse().ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// `request` with its `timestamp` parameter moved forward by `elapsed`, so a
+/// request sent again stays within its `recvWindow`.
+fn shift_timestamp(request: &str, elapsed: Duration) -> String {
+    if elapsed.is_zero() {
+        return request.to_owned();
+    }
+    let elapsed = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+    request
+        .split('&')
+        .map(|param| {
+            match param
+                .strip_prefix("timestamp=")
+                .and_then(|timestamp| timestamp.parse::<u64>().ok())
+            {
+                Some(timestamp) => format!("timestamp={}", timestamp.saturating_add(elapsed)),
+                None => param.to_owned(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
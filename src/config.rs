@@ -1,12 +1,131 @@
+use std::time::Duration;
+
+use error_chain::bail;
+
+use crate::errors::ErrorKind;
+use crate::errors::Result;
+use crate::util::TimeOffset;
+
+/// Largest `recvWindow` accepted by Binance, in milliseconds.
+pub const MAX_RECV_WINDOW: u64 = 60_000;
+
+/// Retries of the requests rejected for exceeding a rate limit, with a
+/// 429, or while the IP is banned for it, with a 418.
+///
+/// A rejected request is sent again once the delay of its `Retry-After`
+/// header elapsed. Signed requests are signed again, their timestamp moved
+/// forward by the time waited, so they stay within their `recvWindow`.
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimitRetry {
+    /// Number of times a request is sent again before its error is returned.
+    pub max_retries: u32,
+    /// Delay used when the response has no `Retry-After` header.
+    pub default_delay: Duration,
+    /// Longest delay waited, the error is returned right away for longer
+    /// bans.
+    pub max_delay: Duration,
+}
+
+impl Default for RateLimitRetry {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            default_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Algorithm of the API key, signing the requests with its secret key.
+///
+/// The secret key of an Ed25519 or RSA API key is its PEM private key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SignatureKind {
+    #[default]
+    Hmac,
+    Ed25519,
+    Rsa,
+}
+
+/// Retries of the GET requests failing with a 500, 502 or 503, or on a
+/// connection error, as during the maintenance windows.
+///
+/// Only GET requests are sent again, an order may have been placed or
+/// canceled by a POST or DELETE that failed. Signed GET requests are signed
+/// again, their timestamp moved forward by the time waited, so the backoff
+/// does not get them rejected for an expired `recvWindow`.
+#[derive(Clone, Copy, Debug)]
+pub struct ServerErrorRetry {
+    /// Number of times a request is sent again before its error is returned.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each of the next ones.
+    pub base_delay: Duration,
+    /// Longest delay between two retries.
+    pub max_delay: Duration,
+}
+
+impl ServerErrorRetry {
+    /// Delay before the `retry`th retry, starting at 1.
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(retry.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+impl Default for ServerErrorRetry {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub rest_api_endpoint: String,
+    /// Websocket endpoint of the spot streams.
     pub ws_endpoint: String,
 
     pub futures_rest_api_endpoint: String,
+    /// Websocket endpoint of the USD-M futures streams.
     pub futures_ws_endpoint: String,
 
+    pub delivery_rest_api_endpoint: String,
+    /// Websocket endpoint of the COIN-M futures streams.
+    pub delivery_ws_endpoint: String,
+
+    /// Websocket endpoint of the vanilla options streams.
+    pub vanilla_ws_endpoint: String,
+
     pub recv_window: u64,
+
+    /// Timeout of the REST requests, from connecting to reading the whole
+    /// response.
+    pub timeout: Duration,
+
+    pub rate_limit_retry: Option<RateLimitRetry>,
+
+    pub server_error_retry: Option<ServerErrorRetry>,
+
+    /// Send the parameters of the signed POST requests, such as orders, in
+    /// a form encoded body rather than in the query string.
+    pub post_params_in_body: bool,
+
+    /// Offset in milliseconds of the server clock over the local clock,
+    /// added to the `timestamp` of the signed requests.
+    ///
+    /// It is shared by the clients built from this configuration and its
+    /// clones, and updated by `General::sync_time`.
+    pub time_offset: TimeOffset,
+
+    pub signature_kind: SignatureKind,
+
+    /// HTTP client sending the REST requests instead of one built from this
+    /// configuration, to set a proxy or share a connection pool.
+    pub http_client: Option<reqwest::Client>,
 }
 
 impl Default for Config {
@@ -18,19 +137,58 @@ impl Default for Config {
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com/ws".into(),
 
+            delivery_rest_api_endpoint: "https://dapi.binance.com".into(),
+            delivery_ws_endpoint: "wss://dstream.binance.com/ws".into(),
+
+            vanilla_ws_endpoint: "wss://vstream.binance.com/ws".into(),
+
             recv_window: 5000,
+
+            timeout: Duration::from_secs(10),
+
+            rate_limit_retry: None,
+
+            server_error_retry: None,
+
+            post_params_in_body: false,
+
+            time_offset: TimeOffset::default(),
+
+            signature_kind: SignatureKind::Hmac,
+
+            http_client: None,
         }
     }
 }
 
 impl Config {
+    /// Configuration of the spot and futures testnets.
     #[must_use]
     pub fn testnet() -> Self {
+        Self::futures_testnet().with_spot_testnet()
+    }
+
+    /// Configuration of the spot testnet, the futures endpoints being those
+    /// of the live exchange.
+    #[must_use]
+    pub fn spot_testnet() -> Self {
+        Self::default().with_spot_testnet()
+    }
+
+    /// Configuration of the USD-M and COIN-M futures testnet, the spot
+    /// endpoints being those of the live exchange.
+    #[must_use]
+    pub fn futures_testnet() -> Self {
         Self::default()
-            .set_rest_api_endpoint("https://testnet.binance.vision")
-            .set_ws_endpoint("wss://testnet.binance.vision/ws")
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
-            .set_futures_ws_endpoint("https://testnet.binancefuture.com/ws")
+            .set_futures_ws_endpoint("wss://fstream.binancefuture.com/ws")
+            .set_delivery_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_delivery_ws_endpoint("wss://dstream.binancefuture.com/ws")
+    }
+
+    fn with_spot_testnet(self) -> Self {
+        self.set_rest_api_endpoint("https://testnet.binance.vision")
+            .set_ws_endpoint("wss://testnet.binance.vision/ws")
     }
 
     /// Sets the rest api endpoint of this [`Config`].
@@ -64,9 +222,118 @@ impl Config {
         self
     }
 
+    /// Sets the delivery (COIN-M futures) rest api endpoint of this
+    /// [`Config`].
+    #[must_use]
+    pub fn set_delivery_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        delivery_rest_api_endpoint: T,
+    ) -> Self {
+        self.delivery_rest_api_endpoint = delivery_rest_api_endpoint.into();
+        self
+    }
+
+    /// Sets the delivery (COIN-M futures) ws endpoint of this [`Config`].
+    #[must_use]
+    pub fn set_delivery_ws_endpoint<T: Into<String>>(mut self, delivery_ws_endpoint: T) -> Self {
+        self.delivery_ws_endpoint = delivery_ws_endpoint.into();
+        self
+    }
+
+    /// Sets the vanilla options ws endpoint of this [`Config`].
+    #[must_use]
+    pub fn set_vanilla_ws_endpoint<T: Into<String>>(mut self, vanilla_ws_endpoint: T) -> Self {
+        self.vanilla_ws_endpoint = vanilla_ws_endpoint.into();
+        self
+    }
+
+    /// Sets the recv window, in milliseconds, of this [`Config`].
+    ///
+    /// A value above [`MAX_RECV_WINDOW`] is rejected when a request is
+    /// signed, use [`Config::try_set_recv_window`] to check it right away.
     #[must_use]
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
     }
+
+    /// Sets the recv window, in milliseconds, of this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `recv_window` exceeds [`MAX_RECV_WINDOW`].
+    pub fn try_set_recv_window(self, recv_window: u64) -> Result<Self> {
+        if recv_window > MAX_RECV_WINDOW {
+            bail!(ErrorKind::InvalidRecvWindow(recv_window));
+        }
+        Ok(self.set_recv_window(recv_window))
+    }
+
+    /// Sets the timeout of the REST requests of this [`Config`].
+    #[must_use]
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the retries of the requests rejected by a rate limit of this
+    /// [`Config`], which fail with `ErrorKind::TooManyRequest` or
+    /// `ErrorKind::IpBanned` by default.
+    #[must_use]
+    pub fn set_rate_limit_retry(mut self, rate_limit_retry: RateLimitRetry) -> Self {
+        self.rate_limit_retry = Some(rate_limit_retry);
+        self
+    }
+
+    /// Sets the signature kind of the API key of this [`Config`].
+    #[must_use]
+    pub fn set_signature_kind(mut self, signature_kind: SignatureKind) -> Self {
+        self.signature_kind = signature_kind;
+        self
+    }
+
+    /// Sets the retries of the GET requests failing with a server error of
+    /// this [`Config`].
+    #[must_use]
+    pub fn set_server_error_retry(mut self, server_error_retry: ServerErrorRetry) -> Self {
+        self.server_error_retry = Some(server_error_retry);
+        self
+    }
+
+    /// Sets whether the signed POST requests of this [`Config`] send their
+    /// parameters in the request body.
+    #[must_use]
+    pub fn set_post_params_in_body(mut self, post_params_in_body: bool) -> Self {
+        self.post_params_in_body = post_params_in_body;
+        self
+    }
+
+    /// Sets the time offset, in milliseconds, of this [`Config`].
+    ///
+    /// The clients built from it start from this offset until
+    /// `General::sync_time` measures a new one.
+    #[must_use]
+    pub fn set_time_offset(mut self, time_offset: i64) -> Self {
+        self.time_offset = TimeOffset::new(time_offset);
+        self
+    }
+
+    /// Sets the time offset of this [`Config`] to a handle shared with
+    /// other configurations, so that the offset measured by one client is
+    /// used by all of them.
+    #[must_use]
+    pub fn set_shared_time_offset(mut self, time_offset: TimeOffset) -> Self {
+        self.time_offset = time_offset;
+        self
+    }
+
+    /// Sets the HTTP client of this [`Config`].
+    ///
+    /// The clients built from this [`Config`] share its connection pool, and
+    /// its own timeouts apply rather than [`Config::timeout`].
+    #[must_use]
+    pub fn set_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
 }
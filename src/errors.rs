@@ -1,10 +1,45 @@
 use error_chain::error_chain;
 use serde::Deserialize;
+use serde::Serialize;
 
-#[derive(Debug, Deserialize)]
+use crate::spot::model::CancelReplaceFailure;
+use crate::spot::model::Order;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BinanceContentError {
     pub code: i16,
     pub msg: String,
+    /// Details sent along with some errors, such as the outcome of a
+    /// partially failed cancel-replace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+impl BinanceContentError {
+    /// Whether the error reports a temporary condition of the exchange
+    /// rather than a problem with the request itself.
+    ///
+    /// Transient errors are disconnections (-1001), overloaded servers
+    /// (-1008) and services shutting down (-1016): the same request may
+    /// succeed when retried. Everything else, such as invalid parameters or
+    /// an insufficient balance, fails again until the request is changed.
+    ///
+    /// Unexpected responses (-1006) and timeouts (-1007) are not transient,
+    /// see [`BinanceContentError::is_execution_unknown`].
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(self.code, -1001 | -1008 | -1016)
+    }
+
+    /// Whether the request may or may not have been executed, after an
+    /// unexpected response (-1006) or a timeout (-1007) of the exchange.
+    ///
+    /// An order placed with such an error may be live: look it up by its
+    /// client order id before sending it again.
+    #[must_use]
+    pub fn is_execution_unknown(&self) -> bool {
+        matches!(self.code, -1006 | -1007)
+    }
 }
 
 error_chain! {
@@ -13,10 +48,50 @@ error_chain! {
 
         TooManyRequest
 
+        IpBanned(retry_after: Option<std::time::Duration>) {
+            description("IP banned for exceeding the rate limits"),
+            display("IP banned for exceeding the rate limits, retry after {:?}", retry_after),
+        }
+
         KlineValueMissingError(index: usize, name: &'static str) {
             description("invalid Vec for Kline"),
             display("{} at {} is missing", name, index),
         }
+
+        InvalidRecvWindow(recv_window: u64) {
+            description("invalid recvWindow"),
+            display("recvWindow of {} ms exceeds the maximum of 60000 ms", recv_window),
+        }
+
+        OrderBookGap(last_update_id: u64, first_update_id: u64) {
+            description("gap in the order book updates"),
+            display("Order book updates missing after {}, next event starts at {}", last_update_id, first_update_id),
+        }
+
+        WebsocketTimeout(timeout: std::time::Duration) {
+            description("websocket receive timed out"),
+            display("No message received from the websocket within {:?}", timeout),
+        }
+
+        InvalidSigningKey(reason: String) {
+            description("invalid signing key"),
+            display("Invalid private key for signing requests: {}", reason),
+        }
+
+        CancelReplacePartiallyFailed(failure: Box<CancelReplaceFailure>) {
+            description("cancel-replace partially failed"),
+            display("Order cancel-replace partially failed: cancel {}, new order {}", failure.cancel_result, failure.new_order_result),
+        }
+
+        OrderTimeout(order: Option<Box<Order>>, timeout: std::time::Duration) {
+            description("order not terminal before the timeout"),
+            display("Order {} after {:?}", order.as_ref().map_or_else(|| "not acknowledged".to_owned(), |order| format!("{} is still {}", order.order_id, order.status)), timeout),
+        }
+
+        GeoRestricted(status: u16, body: String) {
+            description("request blocked from this region"),
+            display("Request blocked from this region or IP (HTTP {}): {}", status, body),
+        }
      }
 
     foreign_links {
@@ -24,6 +99,7 @@ error_chain! {
         InvalidHeaderError(reqwest::header::InvalidHeaderValue);
         IoError(std::io::Error);
         ParseFloatError(std::num::ParseFloatError);
+        DecimalError(rust_decimal::Error);
         UrlParserError(url::ParseError);
         Json(serde_json::Error);
         Tungstenite(tokio_tungstenite::tungstenite::Error);
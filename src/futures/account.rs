@@ -1,22 +1,31 @@
 use std::collections::BTreeMap;
 use std::fmt::Display;
 
+use error_chain::bail;
+
 use super::model::AccountBalance;
 use super::model::AccountInformation;
 use super::model::CanceledOrder;
 use super::model::ChangeLeverageResponse;
+use super::model::PositionMarginHistory;
+use super::model::PositionMarginResponse;
+use super::model::PositionMode;
 use super::model::PositionRisk;
 use super::model::Transaction;
 use crate::api::Futures;
 use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::ErrorKind;
 use crate::errors::Result;
 use crate::futures::model::Order;
 use crate::futures::model::TradeHistory;
 use crate::model::Empty;
 use crate::spot::account::OrderSide;
-use crate::util::build_signed_request;
+use crate::util::trace_order_canceled;
+use crate::util::trace_order_placed;
+use crate::util::trace_order_rejected;
 
 #[derive(Clone)]
 pub struct Account {
@@ -60,6 +69,36 @@ impl Display for PositionSide {
     }
 }
 
+/// Direction of an isolated position margin change.
+pub enum PositionMarginType {
+    Add,
+    Reduce,
+}
+
+impl Display for PositionMarginType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "1"),
+            Self::Reduce => write!(f, "2"),
+        }
+    }
+}
+
+/// Margin mode of a symbol's positions.
+pub enum MarginType {
+    Isolated,
+    Crossed,
+}
+
+impl Display for MarginType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Isolated => write!(f, "ISOLATED"),
+            Self::Crossed => write!(f, "CROSSED"),
+        }
+    }
+}
+
 pub enum OrderType {
     Limit,
     Market,
@@ -98,6 +137,11 @@ impl Display for WorkingType {
     }
 }
 
+/// How long a futures order stays active.
+///
+/// `GTX` (good till crossing) makes a limit order post-only: it is rejected
+/// instead of taking liquidity. Spot has no `GTX`, use
+/// `spot::account::OrderType::LimitMaker` there.
 #[allow(clippy::all)]
 pub enum TimeInForce {
     GTC,
@@ -117,6 +161,37 @@ impl Display for TimeInForce {
     }
 }
 
+/// Price matching mode of an order.
+///
+/// The order price follows the order book instead of being fixed, so it
+/// cannot be combined with an explicit price.
+#[allow(non_camel_case_types)]
+pub enum PriceMatch {
+    OPPONENT,
+    OPPONENT_5,
+    OPPONENT_10,
+    OPPONENT_20,
+    QUEUE,
+    QUEUE_5,
+    QUEUE_10,
+    QUEUE_20,
+}
+
+impl Display for PriceMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OPPONENT => write!(f, "OPPONENT"),
+            Self::OPPONENT_5 => write!(f, "OPPONENT_5"),
+            Self::OPPONENT_10 => write!(f, "OPPONENT_10"),
+            Self::OPPONENT_20 => write!(f, "OPPONENT_20"),
+            Self::QUEUE => write!(f, "QUEUE"),
+            Self::QUEUE_5 => write!(f, "QUEUE_5"),
+            Self::QUEUE_10 => write!(f, "QUEUE_10"),
+            Self::QUEUE_20 => write!(f, "QUEUE_20"),
+        }
+    }
+}
+
 struct OrderRequest {
     pub symbol: String,
     pub side: OrderSide,
@@ -132,6 +207,7 @@ struct OrderRequest {
     pub callback_rate: Option<f64>,
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<f64>,
+    pub price_match: Option<PriceMatch>,
 }
 
 pub struct CustomOrderRequest {
@@ -149,6 +225,7 @@ pub struct CustomOrderRequest {
     pub callback_rate: Option<f64>,
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<f64>,
+    pub price_match: Option<PriceMatch>,
 }
 
 pub struct IncomeRequest {
@@ -237,6 +314,7 @@ impl Account {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             )?,
             recv_window: config.recv_window,
         })
@@ -269,12 +347,10 @@ impl Account {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let order = build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
     }
 
     /// Places a limit sell order.
@@ -304,12 +380,10 @@ impl Account {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
     }
 
     /// Places a market buy order.
@@ -337,12 +411,10 @@ impl Account {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let order = build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
     }
 
     /// Place a sell market order.
@@ -370,12 +442,10 @@ impl Account {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let order = build_order(sell_order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
     }
 
     /// Cancels an order.
@@ -391,10 +461,20 @@ impl Account {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("orderId".into(), order_id.to_string());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        self.client
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let canceled: CanceledOrder = self
+            .client
             .delete_signed(API::Futures(Futures::Order), Some(request))
-            .await
+            .await?;
+        trace_order_canceled(
+            &canceled.symbol,
+            Some(&canceled.client_order_id),
+            Some(canceled.order_id),
+            Some(&canceled.status),
+        );
+        Ok(canceled)
     }
 
     /// Cancels an order by client id.
@@ -414,10 +494,20 @@ impl Account {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("origClientOrderId".into(), orig_client_order_id);
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        self.client
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let canceled: CanceledOrder = self
+            .client
             .delete_signed(API::Futures(Futures::Order), Some(request))
-            .await
+            .await?;
+        trace_order_canceled(
+            &canceled.symbol,
+            Some(&canceled.client_order_id),
+            Some(canceled.order_id),
+            Some(&canceled.status),
+        );
+        Ok(canceled)
     }
 
     /// Place a `OrderType::StopMarket` buy order.
@@ -445,12 +535,10 @@ impl Account {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
     }
 
     /// Place a `OrderType::StopMarket` sell order.
@@ -482,20 +570,22 @@ impl Account {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
     }
 
     /// Custom order for  professional traders
     ///
     /// # Errors
     ///
-    /// Returns an error if the order placement fails.
+    /// Returns an error if the order placement fails, or if both `price` and
+    /// `price_match` are set.
     pub async fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
+        if order_request.price.is_some() && order_request.price_match.is_some() {
+            bail!("An order cannot have both a price and a price match");
+        }
         let order = OrderRequest {
             symbol: order_request.symbol,
             side: order_request.side,
@@ -511,12 +601,23 @@ impl Account {
             callback_rate: order_request.callback_rate,
             working_type: order_request.working_type,
             price_protect: order_request.price_protect,
+            price_match: order_request.price_match,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Futures(Futures::Order), request)
-            .await
+        self.post_order(order).await
+    }
+
+    /// Places a new order.
+    ///
+    /// Supports every order type together with `positionSide`, `reduceOnly`,
+    /// `closePosition`, `workingType` and `timeInForce`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order placement fails, or if both `price` and
+    /// `price_match` are set.
+    pub async fn place_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
+        self.custom_order(order_request).await
     }
 
     /// Get all orders
@@ -552,7 +653,9 @@ impl Account {
             parameters.insert("limit".into(), limit.to_string());
         }
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::AllOrders), Some(request))
             .await
@@ -591,25 +694,31 @@ impl Account {
             parameters.insert("limit".into(), limit.to_string());
         }
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::UserTrades), Some(request))
             .await
     }
 
-    /// Get open positions information.
+    /// Get open positions information, for `symbol` or for all symbols.
     ///
     /// # Errors
     ///
     /// Returns an error if sending the request fails.
-    pub async fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
+    pub async fn position_information<'a, S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
     where
-        S: Into<String>,
+        S: Into<Option<&'a str>>,
     {
         let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol.into());
+        }
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::PositionRisk), Some(request))
             .await
@@ -623,7 +732,9 @@ impl Account {
     pub async fn account_information(&self) -> Result<AccountInformation> {
         let parameters = BTreeMap::new();
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::Account), Some(request))
             .await
@@ -637,7 +748,9 @@ impl Account {
     pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
         let parameters = BTreeMap::new();
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::Balance), Some(request))
             .await
@@ -647,7 +760,8 @@ impl Account {
     ///
     /// # Errors
     ///
-    /// Returns an error if sending the request fails.
+    /// Returns an error if `leverage` is outside `1..=125`, or if sending the
+    /// request fails, e.g. because the symbol's bracket rejects the leverage.
     pub async fn change_initial_leverage<S>(
         &self,
         symbol: S,
@@ -656,31 +770,92 @@ impl Account {
     where
         S: Into<String>,
     {
+        if !(1..=125).contains(&leverage) {
+            bail!("Leverage must be between 1 and 125, got {}", leverage);
+        }
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("leverage".into(), leverage.to_string());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::ChangeInitialLeverage), request)
             .await
     }
 
-    /// Change position mode.
+    /// Change the margin type of a symbol.
+    ///
+    /// Switching to the margin type already in use is treated as success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending the request fails, e.g. because the symbol
+    /// has open orders or positions.
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("marginType".into(), margin_type.to_string());
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        match self
+            .client
+            .post_signed::<Empty>(API::Futures(Futures::MarginType), request)
+            .await
+        {
+            // -4046: "No need to change margin type."
+            Err(Error(ErrorKind::BinanceError(response), _)) if response.code == -4046 => Ok(()),
+            result => result.map(|_| ()),
+        }
+    }
+
+    /// Get position mode.
     ///
     /// # Errors
     ///
     /// Returns an error if sending the request fails.
+    pub async fn get_position_mode(&self) -> Result<PositionMode> {
+        let parameters = BTreeMap::new();
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionSide), Some(request))
+            .await
+    }
+
+    /// Change position mode.
+    ///
+    /// Switching to the position mode already in use is treated as success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending the request fails, e.g. because there are
+    /// open orders or positions.
     pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         let dual_side = if dual_side_position { "true" } else { "false" };
         parameters.insert("dualSidePosition".into(), dual_side.into());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        self.client
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        match self
+            .client
             .post_signed::<Empty>(API::Futures(Futures::PositionSide), request)
             .await
-            .map(|_| ())
+        {
+            // -4059: "No need to change position side."
+            Err(Error(ErrorKind::BinanceError(response), _)) if response.code == -4059 => Ok(()),
+            result => result.map(|_| ()),
+        }
     }
 
     /// Get all open orders.
@@ -692,13 +867,17 @@ impl Account {
     where
         S: Into<String>,
     {
+        let symbol = symbol.into();
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_signed_request(parameters, self.recv_window)?;
+        parameters.insert("symbol".into(), symbol.clone());
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed::<Empty>(API::Futures(Futures::AllOpenOrders), Some(request))
-            .await
-            .map(|_| ())
+            .await?;
+        trace_order_canceled(&symbol, None, None, None);
+        Ok(())
     }
 
     /// Get all open orders.
@@ -715,7 +894,9 @@ impl Account {
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::OpenOrders), Some(request))
             .await
@@ -747,12 +928,133 @@ impl Account {
             parameters.insert("limit".into(), limit.to_string());
         }
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        println!("{request}");
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::Income), Some(request))
             .await
     }
+
+    /// Get income history, such as realized PnL, funding fees and commissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending the request fails.
+    pub async fn income_history(
+        &self,
+        symbol: Option<String>,
+        income_type: Option<IncomeType>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<crate::futures::model::Income>> {
+        self.get_income(IncomeRequest {
+            symbol,
+            income_type,
+            start_time,
+            end_time,
+            limit,
+        })
+        .await
+    }
+
+    /// Add margin to, or reduce margin of, an isolated position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `amount` is not positive or if sending the
+    /// request fails.
+    pub async fn modify_isolated_margin<S>(
+        &self,
+        symbol: S,
+        amount: f64,
+        position_side: Option<PositionSide>,
+        margin_type: PositionMarginType,
+    ) -> Result<PositionMarginResponse>
+    where
+        S: Into<String>,
+    {
+        if amount.is_nan() || amount <= 0.0 {
+            bail!("Position margin amount must be positive, got {}", amount);
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), margin_type.to_string());
+        if let Some(position_side) = position_side {
+            parameters.insert("positionSide".into(), position_side.to_string());
+        }
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::PositionMargin), request)
+            .await
+    }
+
+    /// Get the margin changes of an isolated position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending the request fails.
+    pub async fn position_margin_history<S, F, N>(
+        &self,
+        symbol: S,
+        margin_type: Option<PositionMarginType>,
+        start_time: F,
+        end_time: F,
+        limit: N,
+    ) -> Result<Vec<PositionMarginHistory>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(margin_type) = margin_type {
+            parameters.insert("type".into(), margin_type.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionMarginHistory), Some(request))
+            .await
+    }
+
+    /// Sign and send an order, emitting its lifecycle event.
+    async fn post_order(&self, order: BTreeMap<String, String>) -> Result<Transaction> {
+        let request = self
+            .client
+            .build_signed_request(order.clone(), self.recv_window)?;
+        let result = self
+            .client
+            .post_signed::<Transaction>(API::Futures(Futures::Order), request)
+            .await;
+        match &result {
+            Ok(transaction) => trace_order_placed(
+                &order,
+                &transaction.client_order_id,
+                transaction.order_id,
+                &transaction.status,
+            ),
+            Err(e) => trace_order_rejected(&order, e),
+        }
+        result
+    }
 }
 
 /// Build order from request.
@@ -801,6 +1103,9 @@ fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
             price_protect.to_string().to_uppercase(),
         );
     }
+    if let Some(price_match) = order.price_match {
+        parameters.insert("priceMatch".into(), price_match.to_string());
+    }
 
     parameters
 }
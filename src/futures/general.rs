@@ -2,15 +2,18 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use error_chain::bail;
+use rust_decimal::Decimal;
 
 use crate::api::Futures;
-use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
 use crate::errors::Result;
 use crate::futures::model::ExchangeInformation;
 use crate::futures::model::Symbol;
+use crate::futures::FuturesProduct;
+use crate::model::Empty;
 use crate::model::ServerTime;
+use crate::util;
 
 const CACHE_TTL: u64 = 600; // 10 minutes.
 
@@ -19,6 +22,7 @@ pub struct General {
     pub client: Client,
     pub(crate) cache: Option<ExchangeInformation>,
     pub(crate) last_update: Option<u64>,
+    pub product: FuturesProduct,
 }
 
 impl General {
@@ -33,7 +37,7 @@ impl General {
         Self::new_with_config(api_key, secret_key, &Config::default())
     }
 
-    /// Create a new General instance with a configuration.
+    /// Create a new USD-M General instance with a configuration.
     /// If `api_key` an `secret_key` are provided, the client will be
     /// authenticated.
     ///
@@ -44,15 +48,32 @@ impl General {
         api_key: Option<String>,
         secret_key: Option<String>,
         config: &Config,
+    ) -> Result<Self> {
+        Self::new_with_product(api_key, secret_key, config, FuturesProduct::USDM)
+    }
+
+    /// Create a new General instance with a configuration for the given
+    /// product.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be created.
+    pub fn new_with_product(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+        product: FuturesProduct,
     ) -> Result<Self> {
         Ok(Self {
             client: Client::new(
                 api_key,
                 secret_key,
-                config.futures_rest_api_endpoint.clone(),
+                product.rest_api_endpoint(config).to_owned(),
+                config,
             )?,
             cache: None,
             last_update: None,
+            product,
         })
     }
 
@@ -62,7 +83,9 @@ impl General {
     ///
     /// Returns an error if the request fails.
     pub async fn ping(&self) -> Result<String> {
-        self.client.get(API::Futures(Futures::Ping), None).await?;
+        self.client
+            .get::<Empty>(self.product.api(Futures::Ping), None)
+            .await?;
         Ok("pong".into())
     }
 
@@ -72,7 +95,21 @@ impl General {
     ///
     /// Returns an error if the request fails.
     pub async fn get_server_time(&self) -> Result<ServerTime> {
-        self.client.get(API::Futures(Futures::Time), None).await
+        self.client.get(self.product.api(Futures::Time), None).await
+    }
+
+    /// Synchronize the timestamps of the signed requests with the server
+    /// clock
+    ///
+    /// See the spot `General::sync_time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn sync_time(&self) -> Result<i64> {
+        let sent = SystemTime::now();
+        let server_time = self.get_server_time().await?;
+        util::sync_time_offset(self.client.time_offset(), sent, server_time.server_time)
     }
 
     /// Obtain exchange information
@@ -101,7 +138,7 @@ impl General {
     pub async fn update_cache(&mut self) -> Result<()> {
         let info: ExchangeInformation = self
             .client
-            .get(API::Futures(Futures::ExchangeInfo), None)
+            .get(self.product.api(Futures::ExchangeInfo), None)
             .await?;
         self.cache = Some(info.clone());
         self.last_update = Some(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs());
@@ -151,4 +188,70 @@ impl General {
             Err(e) => Err(e),
         }
     }
+
+    /// Convert a number of COIN-M contracts to a quantity of coins at
+    /// `price`, using the contract size from the cached exchange information,
+    /// even once [`General::has_cache`] reports it expired
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache was never updated, the symbol is not a
+    /// cached COIN-M symbol, or `price` is not positive.
+    pub fn contracts_to_coins<S>(
+        &self,
+        symbol: S,
+        contracts: Decimal,
+        price: Decimal,
+    ) -> Result<Decimal>
+    where
+        S: Into<String>,
+    {
+        let contract_size = self.contract_size(symbol, price)?;
+        Ok(contracts * contract_size / price)
+    }
+
+    /// Convert a quantity of coins to a number of COIN-M contracts at
+    /// `price`, using the contract size from the cached exchange information,
+    /// even once [`General::has_cache`] reports it expired
+    ///
+    /// The result is not rounded: orders take a whole number of contracts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache was never updated, the symbol is not a
+    /// cached COIN-M symbol, or `price` is not positive.
+    pub fn coins_to_contracts<S>(
+        &self,
+        symbol: S,
+        coins: Decimal,
+        price: Decimal,
+    ) -> Result<Decimal>
+    where
+        S: Into<String>,
+    {
+        let contract_size = self.contract_size(symbol, price)?;
+        Ok(coins * price / contract_size)
+    }
+
+    fn contract_size<S>(&self, symbol: S, price: Decimal) -> Result<Decimal>
+    where
+        S: Into<String>,
+    {
+        if price <= Decimal::ZERO {
+            bail!("Price must be positive");
+        }
+        // Contract sizes do not change, an expired cache still has them.
+        let Some(cache) = &self.cache else {
+            bail!("No cache");
+        };
+        let upper_symbol = symbol.into().to_uppercase();
+        let symbols = &cache.symbols;
+        let Some(symbol) = symbols.iter().find(|s| s.symbol == upper_symbol) else {
+            bail!("Symbol not found")
+        };
+        match symbol.contract_size {
+            Some(contract_size) if contract_size > 0 => Ok(Decimal::from(contract_size)),
+            _ => bail!("Symbol {} has no contract size", upper_symbol),
+        }
+    }
 }
@@ -1,5 +1,11 @@
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 
+use error_chain::bail;
+use futures_util::stream;
+use futures_util::StreamExt;
+
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 use crate::api::Futures;
@@ -7,32 +13,124 @@ use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
 use crate::errors::Result;
+use crate::futures::account::ContractType;
 use crate::futures::model::AggTrades;
+use crate::futures::model::FundingInfo;
+use crate::futures::model::FundingRate;
+use crate::futures::model::IndexInfo;
 use crate::futures::model::LiquidationOrders;
+use crate::futures::model::LongShortRatio;
 use crate::futures::model::MarkPrices;
+use crate::futures::model::MarketSummary;
 use crate::futures::model::OpenInterest;
 use crate::futures::model::OpenInterestHist;
 use crate::futures::model::OrderBook;
 use crate::futures::model::PriceStats;
+use crate::futures::model::TakerLongShortRatio;
 use crate::futures::model::Trades;
 use crate::model::BookTickers;
 use crate::model::KlineSummaries;
 use crate::model::KlineSummary;
+use crate::model::OneOrMany;
 use crate::model::SymbolPrice;
 use crate::model::Tickers;
 use crate::spot::model::Prices;
 use crate::util::build_request;
-use crate::util::build_signed_request;
 
 // TODO
 // Make enums for Strings
 // Add limit parameters to functions
 // Implement all functions
 
+/// Stablecoins, besides USDT, that USD-M symbols are quoted in.
+const QUOTE_ASSETS: [&str; 3] = ["USDC", "BUSD", "FDUSD"];
+
+/// Open interest requests [`Market::market_summary`] keeps in flight when
+/// the caller does not choose.
+const MARKET_SUMMARY_CONCURRENCY: usize = 10;
+
+/// Futures product a [`Market`] is connected to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuturesProduct {
+    /// USD-M futures, served by `fapi`.
+    USDM,
+    /// COIN-M futures, served by `dapi`.
+    COINM,
+}
+
+impl FuturesProduct {
+    /// The rest api endpoint of this product in the given [`Config`].
+    #[must_use]
+    pub fn rest_api_endpoint(self, config: &Config) -> &str {
+        match self {
+            Self::USDM => &config.futures_rest_api_endpoint,
+            Self::COINM => &config.delivery_rest_api_endpoint,
+        }
+    }
+
+    pub(crate) fn api(self, route: Futures) -> API {
+        match self {
+            Self::USDM => API::Futures(route),
+            Self::COINM => API::Delivery(route),
+        }
+    }
+}
+
+/// Depth of a futures order book request.
+///
+/// `Custom` passes any other value through, for limits added by Binance
+/// after this release.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FuturesDepthLimit {
+    Limit5,
+    Limit10,
+    Limit20,
+    Limit50,
+    Limit100,
+    Limit500,
+    Limit1000,
+    Custom(u64),
+}
+
+impl FuturesDepthLimit {
+    /// The `limit` query value.
+    #[must_use]
+    pub fn value(self) -> u64 {
+        match self {
+            Self::Limit5 => 5,
+            Self::Limit10 => 10,
+            Self::Limit20 => 20,
+            Self::Limit50 => 50,
+            Self::Limit100 => 100,
+            Self::Limit500 => 500,
+            Self::Limit1000 => 1000,
+            Self::Custom(limit) => limit,
+        }
+    }
+}
+
+impl From<u64> for FuturesDepthLimit {
+    fn from(limit: u64) -> Self {
+        match limit {
+            5 => Self::Limit5,
+            10 => Self::Limit10,
+            20 => Self::Limit20,
+            50 => Self::Limit50,
+            100 => Self::Limit100,
+            500 => Self::Limit500,
+            1000 => Self::Limit1000,
+            limit => Self::Custom(limit),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Market {
     pub client: Client,
     pub recv_window: u64,
+    pub product: FuturesProduct,
+    usdm_endpoint: String,
+    coinm_endpoint: String,
 }
 
 impl Market {
@@ -45,7 +143,7 @@ impl Market {
         Self::new_with_config(api_key, secret_key, &Config::default())
     }
 
-    /// Creates a new Market instance with a Config.
+    /// Creates a new USD-M Market instance with a Config.
     ///
     /// # Errors
     ///
@@ -54,17 +152,72 @@ impl Market {
         api_key: Option<String>,
         secret_key: Option<String>,
         config: &Config,
+    ) -> Result<Self> {
+        Self::new_with_product(api_key, secret_key, config, FuturesProduct::USDM)
+    }
+
+    /// Creates a new Market instance with a Config for the given product.
+    ///
+    /// COIN-M markets are served from `config.delivery_rest_api_endpoint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Client fails to be created.
+    pub fn new_with_product(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+        product: FuturesProduct,
     ) -> Result<Self> {
         Ok(Self {
             client: Client::new(
                 api_key,
                 secret_key,
-                config.futures_rest_api_endpoint.clone(),
+                product.rest_api_endpoint(config).to_owned(),
+                config,
             )?,
             recv_window: config.recv_window,
+            product,
+            usdm_endpoint: config.futures_rest_api_endpoint.clone(),
+            coinm_endpoint: config.delivery_rest_api_endpoint.clone(),
         })
     }
 
+    /// Returns a Market for the given product, sharing the credentials and
+    /// connection pool of this one.
+    ///
+    /// The endpoints are those of the Config this Market was created with,
+    /// so a single Market can query both products:
+    ///
+    /// ```no_run
+    /// # async fn run() -> binance::errors::Result<()> {
+    /// use binance::futures::market::Market;
+    /// use binance::futures::FuturesProduct;
+    ///
+    /// let market = Market::new(None, None)?;
+    /// let usdm = market.get_depth("BTCUSDT").await?;
+    /// let coinm = market
+    ///     .for_product(FuturesProduct::COINM)
+    ///     .get_depth("BTCUSD_PERP")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn for_product(&self, product: FuturesProduct) -> Self {
+        let endpoint = match product {
+            FuturesProduct::USDM => &self.usdm_endpoint,
+            FuturesProduct::COINM => &self.coinm_endpoint,
+        };
+        Self {
+            client: self.client.with_host(endpoint.clone()),
+            recv_window: self.recv_window,
+            product,
+            usdm_endpoint: self.usdm_endpoint.clone(),
+            coinm_endpoint: self.coinm_endpoint.clone(),
+        }
+    }
+
     /// Order book (Default 100; max 1000)
     ///
     /// # Errors
@@ -80,26 +233,27 @@ impl Market {
         let request = build_request(parameters);
 
         self.client
-            .get(API::Futures(Futures::Depth), Some(request))
+            .get(self.product.api(Futures::Depth), Some(request))
             .await
     }
 
     /// Order book at a custom depth. Currently supported values
-    /// are 5, 10, 20, 50, 100, 500, 1000
+    /// are 5, 10, 20, 50, 100, 500, 1000, see [`FuturesDepthLimit`]
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails.
-    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    pub async fn get_custom_depth<S, D>(&self, symbol: S, depth: D) -> Result<OrderBook>
     where
         S: Into<String>,
+        D: Into<FuturesDepthLimit>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("limit".into(), depth.to_string());
+        parameters.insert("limit".into(), depth.into().value().to_string());
         let request = build_request(parameters);
         self.client
-            .get(API::Futures(Futures::Depth), Some(request))
+            .get(self.product.api(Futures::Depth), Some(request))
             .await
     }
 
@@ -116,7 +270,7 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
         self.client
-            .get(API::Futures(Futures::Trades), Some(request))
+            .get(self.product.api(Futures::Trades), Some(request))
             .await
     }
 
@@ -148,10 +302,12 @@ impl Market {
             parameters.insert("fromId".into(), format!("{fi}"));
         }
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
 
         self.client
-            .get_signed(API::Futures(Futures::HistoricalTrades), Some(request))
+            .get_signed(self.product.api(Futures::HistoricalTrades), Some(request))
             .await
     }
 
@@ -196,7 +352,7 @@ impl Market {
         let request = build_request(parameters);
 
         self.client
-            .get(API::Futures(Futures::AggTrades), Some(request))
+            .get(self.product.api(Futures::AggTrades), Some(request))
             .await
     }
 
@@ -226,14 +382,211 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("interval".into(), interval.into());
 
+        self.get_klines_from(
+            Futures::Klines,
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Returns up to 'limit' klines of a contract type of a pair, e.g. the
+    /// current quarter contract of "BTCUSDT", for the given interval
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_klines_from(
+            Futures::ContinuousKlines,
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Returns up to 'limit' index price klines of a pair for the given
+    /// interval
+    ///
+    /// The volumes of these klines are always zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        pair: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_klines_from(
+            Futures::IndexPriceKlines,
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Returns up to 'limit' mark price klines of a symbol for the given
+    /// interval
+    ///
+    /// The volumes of these klines are always zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_klines_from(
+            Futures::MarkPriceKlines,
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Returns up to 'limit' klines of the net asset value of a leveraged
+    /// token for the given interval
+    ///
+    /// In these klines `volume` holds the real leverage at close and
+    /// `number_of_trades` the number of NAV updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market is not USD-M, or the request fails.
+    pub async fn get_lvt_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        if self.product != FuturesProduct::USDM {
+            bail!("Leveraged token klines are only available for USD-M futures");
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_klines_from(
+            Futures::LvtKlines,
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Composition of a composite index, or of all of them
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market is not USD-M, or the request fails.
+    pub async fn get_index_info(&self, symbol: Option<String>) -> Result<Vec<IndexInfo>> {
+        if self.product != FuturesProduct::USDM {
+            bail!("Composite index info is only available for USD-M futures");
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        let request = build_request(parameters);
+
+        // A single symbol is answered with an object rather than a list.
+        Ok(self
+            .client
+            .get::<OneOrMany<IndexInfo>>(self.product.api(Futures::IndexInfo), Some(request))
+            .await?
+            .into_vec())
+    }
+
+    async fn get_klines_from(
+        &self,
+        route: Futures,
+        mut parameters: BTreeMap<String, String>,
+        limit: Option<u16>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<KlineSummaries> {
         // Add three optional parameters
-        if let Some(lt) = limit.into() {
+        if let Some(lt) = limit {
             parameters.insert("limit".into(), format!("{lt}"));
         }
-        if let Some(st) = start_time.into() {
+        if let Some(st) = start_time {
             parameters.insert("startTime".into(), format!("{st}"));
         }
-        if let Some(et) = end_time.into() {
+        if let Some(et) = end_time {
             parameters.insert("endTime".into(), format!("{et}"));
         }
 
@@ -241,7 +594,7 @@ impl Market {
 
         let data: Vec<Vec<Value>> = self
             .client
-            .get(API::Futures(Futures::Klines), Some(request))
+            .get(self.product.api(route), Some(request))
             .await?;
 
         let klines = KlineSummaries::AllKlineSummaries(
@@ -267,9 +620,11 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
 
+        // COIN-M answers with a list, even for a single symbol.
         self.client
-            .get(API::Futures(Futures::Ticker24hr), Some(request))
-            .await
+            .get::<OneOrMany<PriceStats>>(self.product.api(Futures::Ticker24hr), Some(request))
+            .await?
+            .into_one()
     }
 
     /// 24hr ticker price change statistics for all symbols
@@ -279,7 +634,7 @@ impl Market {
     /// Returns an error if the request fails.
     pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
         self.client
-            .get(API::Futures(Futures::Ticker24hr), None)
+            .get(self.product.api(Futures::Ticker24hr), None)
             .await
     }
 
@@ -298,8 +653,9 @@ impl Market {
         let request = build_request(parameters);
 
         self.client
-            .get(API::Futures(Futures::TickerPrice), Some(request))
-            .await
+            .get::<OneOrMany<SymbolPrice>>(self.product.api(Futures::TickerPrice), Some(request))
+            .await?
+            .into_one()
     }
 
     /// Latest price for all symbols.
@@ -309,7 +665,7 @@ impl Market {
     /// Returns an error if the request fails.
     pub async fn get_all_prices(&self) -> Result<Prices> {
         self.client
-            .get(API::Futures(Futures::TickerPrice), None)
+            .get(self.product.api(Futures::TickerPrice), None)
             .await
     }
 
@@ -321,7 +677,7 @@ impl Market {
     /// Returns an error if the request fails.
     pub async fn get_all_book_tickers(&self) -> Result<BookTickers> {
         self.client
-            .get(API::Futures(Futures::BookTicker), None)
+            .get(self.product.api(Futures::BookTicker), None)
             .await
     }
 
@@ -338,8 +694,9 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
         self.client
-            .get(API::Futures(Futures::BookTicker), Some(request))
-            .await
+            .get::<OneOrMany<Tickers>>(self.product.api(Futures::BookTicker), Some(request))
+            .await?
+            .into_one()
     }
 
     /// Mark price and funding rate
@@ -349,7 +706,61 @@ impl Market {
     /// Returns an error if the request fails.
     pub async fn get_mark_prices(&self) -> Result<MarkPrices> {
         self.client
-            .get(API::Futures(Futures::PremiumIndex), None)
+            .get(self.product.api(Futures::PremiumIndex), None)
+            .await
+    }
+
+    /// Funding interval and funding rate bounds of the symbols that have
+    /// been adjusted from the defaults
+    ///
+    /// Symbols missing from the list fund every 8 hours.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn funding_info(&self) -> Result<Vec<FundingInfo>> {
+        self.client
+            .get(self.product.api(Futures::FundingInfo), None)
+            .await
+    }
+
+    /// Funding rate history of a symbol, oldest first
+    ///
+    /// Without a time range the most recent rates are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn get_funding_rate_history<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{st}"));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{et}"));
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{lt}"));
+        }
+
+        let request = build_request(parameters);
+
+        self.client
+            .get(self.product.api(Futures::FundingRate), Some(request))
             .await
     }
 
@@ -360,7 +771,7 @@ impl Market {
     /// Returns an error if the request fails.
     pub async fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders> {
         self.client
-            .get(API::Futures(Futures::AllForceOrders), None)
+            .get(self.product.api(Futures::AllForceOrders), None)
             .await
     }
 
@@ -377,10 +788,79 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
         self.client
-            .get(API::Futures(Futures::OpenInterest), Some(request))
+            .get(self.product.api(Futures::OpenInterest), Some(request))
             .await
     }
 
+    /// Open interest and 24h quote volume summed over every traded USD-M
+    /// symbol, in USDT
+    ///
+    /// Symbols quoted in another stablecoin are converted at the last price
+    /// of its `USDT` pair; symbols without such a pair, or without a trade
+    /// in the last 24h, are left out. The open interest of each symbol is
+    /// requested separately, at most `concurrency` at a time, 10 if `None`.
+    /// A symbol whose open interest request fails is left out of both totals
+    /// and listed in `failed_symbols` instead of failing the summary.
+    ///
+    /// Scanning every symbol is expensive: the 24h statistics of all symbols
+    /// weigh 40, plus a weight of 1 per symbol for its open interest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market is not USD-M, or the 24h statistics
+    /// request fails.
+    pub async fn market_summary<C>(&self, concurrency: C) -> Result<MarketSummary>
+    where
+        C: Into<Option<usize>>,
+    {
+        if self.product != FuturesProduct::USDM {
+            bail!("Market summary is only available for USD-M futures");
+        }
+        let stats = self.get_all_24h_price_stats().await?;
+        let last_prices: HashMap<&str, f64> = stats
+            .iter()
+            .map(|stats| (stats.symbol.as_str(), stats.last_price))
+            .collect();
+        let usdt_rate = |symbol: &str| {
+            let pair = symbol.split('_').next().unwrap_or(symbol);
+            if pair.ends_with("USDT") {
+                return Some(1.0);
+            }
+            QUOTE_ASSETS
+                .iter()
+                .find(|quote| pair.ends_with(*quote))
+                .and_then(|quote| last_prices.get(format!("{quote}USDT").as_str()).copied())
+        };
+        let traded: Vec<(&PriceStats, f64)> = stats
+            .iter()
+            .filter(|stats| stats.count > 0)
+            .filter_map(|stats| usdt_rate(&stats.symbol).map(|rate| (stats, rate)))
+            .collect();
+
+        let concurrency = concurrency.into().unwrap_or(MARKET_SUMMARY_CONCURRENCY);
+        let open_interests: Vec<(&PriceStats, f64, Result<OpenInterest>)> = stream::iter(&traded)
+            .map(|&(stats, rate)| async move {
+                (stats, rate, self.open_interest(stats.symbol.as_str()).await)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut summary = MarketSummary::default();
+        for (stats, rate, open_interest) in open_interests {
+            match open_interest {
+                Ok(open_interest) => {
+                    summary.total_oi_usdt += open_interest.open_interest * stats.last_price * rate;
+                    summary.total_volume_usdt += stats.quote_volume * rate;
+                    summary.symbol_count += 1;
+                }
+                Err(_) => summary.failed_symbols.push(stats.symbol.clone()),
+            }
+        }
+        summary.failed_symbols.sort();
+        Ok(summary)
+    }
+
     /// Get open interest statistics
     ///
     /// # Errors
@@ -401,23 +881,167 @@ impl Market {
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
     {
+        self.get_statistics(
+            Futures::OpenInterestHist,
+            symbol.into(),
+            period.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Long/short account ratio of the top traders
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn top_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        period: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortAccountRatio,
+            symbol.into(),
+            period.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Long/short position ratio of the top traders
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn top_long_short_position_ratio<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        period: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortPositionRatio,
+            symbol.into(),
+            period.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Long/short account ratio of all traders
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn global_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        period: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::GlobalLongShortAccountRatio,
+            symbol.into(),
+            period.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    /// Taker buy/sell volume ratio
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn taker_long_short_ratio<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        period: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<TakerLongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TakerlongshortRatio,
+            symbol.into(),
+            period.into(),
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+        .await
+    }
+
+    async fn get_statistics<T: DeserializeOwned>(
+        &self,
+        route: Futures,
+        symbol: String,
+        period: String,
+        limit: Option<u16>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<Vec<T>> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("period".into(), period.into());
+        parameters.insert("symbol".into(), symbol);
+        parameters.insert("period".into(), period);
 
-        if let Some(lt) = limit.into() {
+        if let Some(lt) = limit {
             parameters.insert("limit".into(), format!("{lt}"));
         }
-        if let Some(st) = start_time.into() {
+        if let Some(st) = start_time {
             parameters.insert("startTime".into(), format!("{st}"));
         }
-        if let Some(et) = end_time.into() {
+        if let Some(et) = end_time {
             parameters.insert("endTime".into(), format!("{et}"));
         }
 
         let request = build_request(parameters);
         self.client
-            .get(API::Futures(Futures::OpenInterestHist), Some(request))
+            .get(self.product.api(route), Some(request))
             .await
     }
 }
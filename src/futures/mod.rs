@@ -7,6 +7,7 @@ pub mod websockets;
 
 pub use account::Account;
 pub use general::General;
+pub use market::FuturesProduct;
 pub use market::Market;
 pub use user_stream::UserStream;
 pub use websockets::WebSockets;
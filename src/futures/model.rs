@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -7,6 +8,7 @@ use crate::model::string_or_float_opt;
 use crate::model::Asks;
 use crate::model::Bids;
 use crate::model::Filters;
+use crate::model::OrderBookLevels;
 use crate::model::RateLimit;
 use crate::model::SymbolInfo;
 
@@ -24,6 +26,8 @@ pub struct ExchangeInformation {
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
+    // COIN-M names it `contractStatus`
+    #[serde(alias = "contractStatus")]
     pub status: String,
     pub maint_margin_percent: String,
     pub required_margin_percent: String,
@@ -37,6 +41,9 @@ pub struct Symbol {
     pub order_types: Vec<String>,
     pub time_in_force: Vec<String>,
     pub onboard_date: u128,
+    /// USD value of one contract, COIN-M only
+    #[serde(default)]
+    pub contract_size: Option<u32>,
 }
 
 impl SymbolInfo for Symbol {
@@ -63,6 +70,16 @@ pub struct OrderBook {
     pub asks: Vec<Asks>,
 }
 
+impl OrderBookLevels for OrderBook {
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
@@ -80,8 +97,12 @@ pub struct PriceStats {
     pub low_price: f64,
     #[serde(with = "string_or_float")]
     pub volume: f64,
-    #[serde(with = "string_or_float")]
+    /// USD-M only, zero for COIN-M statistics.
+    #[serde(default, with = "string_or_float")]
     pub quote_volume: f64,
+    /// COIN-M only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub base_volume: Option<f64>,
     #[serde(with = "string_or_float")]
     pub last_qty: f64,
     pub open_time: u64,
@@ -130,8 +151,12 @@ pub struct Trade {
     pub price: f64,
     #[serde(with = "string_or_float")]
     pub qty: f64,
-    #[serde(with = "string_or_float")]
+    /// USD-M only, zero for COIN-M trades.
+    #[serde(default, with = "string_or_float")]
     pub quote_qty: f64,
+    /// COIN-M only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub base_qty: Option<f64>,
     pub time: u64,
 }
 
@@ -166,6 +191,51 @@ pub enum MarkPrices {
     AllMarkPrices(Vec<MarkPrice>),
 }
 
+/// Funding settings of a symbol whose funding interval or funding rate
+/// bounds differ from the defaults.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub adjusted_funding_rate_cap: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub adjusted_funding_rate_floor: Decimal,
+    pub funding_interval_hours: u32,
+}
+
+/// Composition of a composite index.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexInfo {
+    pub symbol: String,
+    pub time: u64,
+    pub component: String,
+    pub base_asset_list: Vec<IndexComponent>,
+}
+
+/// Weight of an asset in a composite index.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexComponent {
+    pub base_asset: String,
+    pub quote_asset: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub weight_in_quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub weight_in_percentage: Decimal,
+}
+
+/// A funding rate settled for a symbol.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    pub funding_time: u64,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub funding_rate: Decimal,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkPrice {
@@ -211,6 +281,18 @@ pub struct OpenInterest {
     pub symbol: String,
 }
 
+/// Open interest and 24h volume summed over the USD-M symbols, see
+/// `Market::market_summary`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketSummary {
+    pub total_oi_usdt: f64,
+    pub total_volume_usdt: f64,
+    /// Symbols summed in the totals
+    pub symbol_count: usize,
+    /// Symbols left out because their open interest request failed
+    pub failed_symbols: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestHist {
@@ -220,6 +302,36 @@ pub struct OpenInterestHist {
     pub timestamp: u64,
 }
 
+/// Long/short ratio of accounts or positions at a point in time.
+///
+/// For the position ratio of top traders, `long_account` and `short_account`
+/// hold the shares of long and short positions.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub long_short_ratio: Decimal,
+    #[serde(with = "rust_decimal::serde::str", alias = "longPosition")]
+    pub long_account: Decimal,
+    #[serde(with = "rust_decimal::serde::str", alias = "shortPosition")]
+    pub short_account: Decimal,
+    pub timestamp: u64,
+}
+
+/// Taker buy/sell volume ratio at a point in time.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerLongShortRatio {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub buy_sell_ratio: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub buy_vol: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub sell_vol: Decimal,
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -488,6 +600,14 @@ pub struct AccountBalance {
     pub update_time: u64,
 }
 
+/// Whether the account trades in hedge mode, holding separate LONG and
+/// SHORT positions per symbol.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    pub dual_side_position: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangeLeverageResponse {
@@ -497,6 +617,30 @@ pub struct ChangeLeverageResponse {
     pub symbol: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginResponse {
+    pub amount: f64,
+    pub code: i32,
+    pub msg: String,
+    #[serde(rename = "type")]
+    pub type_name: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginHistory {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub type_name: u8,
+    pub delta_type: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: String,
+}
+
 fn default_stop_price() -> f64 {
     0.0
 }
@@ -626,6 +770,63 @@ pub struct OrderTradeEvent {
     pub order: OrderUpdate,
 }
 
+/// Contract listing, delisting, settlement or bracket change, from the
+/// `!contractInfo` stream
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContractInfoEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "ps")]
+    pub pair: String,
+
+    #[serde(rename = "ct")]
+    pub contract_type: String,
+
+    #[serde(rename = "dt")]
+    pub delivery_date: u64,
+
+    #[serde(rename = "ot")]
+    pub onboard_date: u64,
+
+    #[serde(rename = "cs")]
+    pub contract_status: String,
+
+    /// Only sent when the brackets changed
+    #[serde(rename = "bks", default)]
+    pub brackets: Vec<ContractBracket>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContractBracket {
+    #[serde(rename = "bs")]
+    pub bracket: u16,
+
+    #[serde(rename = "bnf")]
+    pub notional_floor: f64,
+
+    #[serde(rename = "bnc")]
+    pub notional_cap: f64,
+
+    #[serde(rename = "mmr")]
+    pub maint_margin_ratio: f64,
+
+    #[serde(rename = "cf")]
+    pub cum: f64,
+
+    #[serde(rename = "mi")]
+    pub min_leverage: u16,
+
+    #[serde(rename = "ma")]
+    pub max_leverage: u16,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Income {
@@ -37,6 +37,7 @@ impl UserStream {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             )?,
             recv_window: config.recv_window,
         })
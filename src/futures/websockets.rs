@@ -35,6 +35,7 @@ enum WebsocketsApi {
     Default,
     MultiStream,
     Custom(String),
+    CustomMultiStream(String),
 }
 
 pub enum FuturesMarket {
@@ -58,7 +59,23 @@ impl WebsocketsApi {
             WebsocketsApi::MultiStream => {
                 format!("{baseurl}/stream?streams={subscription}")
             }
-            WebsocketsApi::Custom(url) => url,
+            WebsocketsApi::Custom(url) => format!("{url}/{subscription}"),
+            WebsocketsApi::CustomMultiStream(url) => {
+                // Combined streams are served next to the `/ws` raw streams.
+                let baseurl = url.strip_suffix("/ws").unwrap_or(&url);
+                format!("{baseurl}/stream?streams={subscription}")
+            }
+        }
+    }
+}
+
+impl FuturesMarket {
+    /// Websocket endpoint of the market in `config`.
+    fn ws_endpoint(&self, config: &Config) -> String {
+        match self {
+            FuturesMarket::USDM => config.futures_ws_endpoint.clone(),
+            FuturesMarket::COINM => config.delivery_ws_endpoint.clone(),
+            FuturesMarket::Vanilla => config.vanilla_ws_endpoint.clone(),
         }
     }
 }
@@ -85,6 +102,7 @@ pub enum WebsocketEvent {
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
     UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    ContractInfo(model::ContractInfoEvent),
 }
 
 pub struct WebSockets {
@@ -111,6 +129,7 @@ enum Events {
     ContinuousKlineEvent(ContinuousKlineEvent),
     IndexKlineEvent(IndexKlineEvent),
     LiquidationEvent(LiquidationEvent),
+    ContractInfoEvent(model::ContractInfoEvent),
     OrderBook(OrderBook),
     DepthOrderBookEvent(DepthOrderBookEvent),
     UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
@@ -128,6 +147,15 @@ impl WebSockets {
 
     /// Connect to the Binance Websocket API with a custom configuration.
     ///
+    /// USD-M streams connect to the `futures_ws_endpoint` of the
+    /// configuration, COIN-M streams to its `delivery_ws_endpoint` and
+    /// vanilla options streams to its `vanilla_ws_endpoint`.
+    ///
+    /// The subscription is appended to the endpoint, as in
+    /// `wss://fstream.binance.com/ws/btcusdt@aggTrade`. The endpoint used to
+    /// be connected to as is: a configuration holding a full stream URL must
+    /// now hold only the endpoint, such as `wss://fstream.binance.com/ws`.
+    ///
     /// # Errors
     ///
     /// Returns an error if the connection fails.
@@ -136,10 +164,8 @@ impl WebSockets {
         subscription: &str,
         config: &Config,
     ) -> Result<Self> {
-        Self::connect_wss(
-            &WebsocketsApi::Custom(config.ws_endpoint.clone()).params(market, subscription),
-        )
-        .await
+        let api = WebsocketsApi::Custom(market.ws_endpoint(config));
+        Self::connect_wss(&api.params(market, subscription)).await
     }
 
     /// Connect to the Binance Websocket API with multiple streams.
@@ -154,6 +180,25 @@ impl WebSockets {
         Self::connect_wss(&WebsocketsApi::MultiStream.params(market, &endpoints.join("/"))).await
     }
 
+    /// Connect to the Binance Websocket API with multiple streams and a
+    /// custom configuration.
+    ///
+    /// The streams are combined on the `/stream` endpoint next to the
+    /// websocket endpoint of the market in the configuration, see
+    /// [`WebSockets::connect_with_config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection fails.
+    pub async fn connect_multiple_streams_with_config(
+        market: &FuturesMarket,
+        endpoints: &[String],
+        config: &Config,
+    ) -> Result<Self> {
+        let api = WebsocketsApi::CustomMultiStream(market.ws_endpoint(config));
+        Self::connect_wss(&api.params(market, &endpoints.join("/"))).await
+    }
+
     async fn connect_wss(wss: &str) -> Result<Self> {
         let url = Url::parse(wss)?;
         match tokio_tungstenite::connect_async(url).await {
@@ -201,6 +246,7 @@ impl WebSockets {
             Events::ContinuousKlineEvent(v) => WebsocketEvent::ContinuousKline(v),
             Events::IndexKlineEvent(v) => WebsocketEvent::IndexKline(v),
             Events::LiquidationEvent(v) => WebsocketEvent::Liquidation(v),
+            Events::ContractInfoEvent(v) => WebsocketEvent::ContractInfo(v),
             Events::KlineEvent(v) => WebsocketEvent::Kline(v),
             Events::OrderBook(v) => WebsocketEvent::OrderBook(v),
             Events::DepthOrderBookEvent(v) => WebsocketEvent::DepthOrderBook(v),
@@ -0,0 +1,72 @@
+use std::future::Future;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures_util::future::join3;
+
+use crate::api::Futures;
+use crate::api::API;
+use crate::client::Client;
+use crate::config::Config;
+use crate::errors::Result;
+use crate::futures::general::General as FuturesGeneral;
+use crate::model::Empty;
+use crate::spot::general::General as SpotGeneral;
+
+/// Reachability of each REST surface, with the ping round trip time of the
+/// surfaces that answered.
+#[derive(Debug)]
+pub struct HealthReport {
+    pub spot: Result<Duration>,
+    pub futures_um: Result<Duration>,
+    pub futures_cm: Result<Duration>,
+}
+
+impl HealthReport {
+    /// Check if every surface answered its ping
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.spot.is_ok() && self.futures_um.is_ok() && self.futures_cm.is_ok()
+    }
+}
+
+/// Ping the spot, USD-M futures and COIN-M futures REST endpoints of the
+/// configuration concurrently.
+///
+/// A surface whose client cannot be created or whose ping fails is reported
+/// as down with the corresponding error, the other surfaces are unaffected.
+pub async fn health_check(config: &Config) -> HealthReport {
+    let spot = timed(async {
+        SpotGeneral::new_with_config(None, None, config)?
+            .ping()
+            .await
+    });
+    let futures_um = timed(async {
+        FuturesGeneral::new_with_config(None, None, config)?
+            .ping()
+            .await
+    });
+    let futures_cm = timed(async {
+        Client::new(
+            None,
+            None,
+            config.delivery_rest_api_endpoint.clone(),
+            config,
+        )?
+        .get::<Empty>(API::Delivery(Futures::Ping), None)
+        .await
+    });
+
+    let (spot, futures_um, futures_cm) = join3(spot, futures_um, futures_cm).await;
+    HealthReport {
+        spot,
+        futures_um,
+        futures_cm,
+    }
+}
+
+async fn timed<T>(ping: impl Future<Output = Result<T>>) -> Result<Duration> {
+    let start = Instant::now();
+    ping.await?;
+    Ok(start.elapsed())
+}
@@ -23,6 +23,8 @@ pub mod model;
 
 pub mod api;
 pub mod config;
+pub mod health;
+pub mod orderbook;
 pub mod savings;
 
 pub mod futures;
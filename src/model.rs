@@ -1,5 +1,13 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use error_chain::bail;
 use rust_decimal::Decimal;
+use serde::de;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 
 #[derive(Deserialize, Clone)]
@@ -23,7 +31,7 @@ pub struct SymbolPrice {
     pub price: f64,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "filterType")]
 pub enum Filters {
     #[serde(rename = "PRICE_FILTER")]
@@ -126,7 +134,7 @@ pub struct CommissionRates {
     pub discount: Discount,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Commission {
     pub maker: String,
@@ -195,12 +203,133 @@ pub enum SpotFuturesTransferType {
     CoinFuturesToSpot = 4,
 }
 
+/// Source and destination wallets of a universal transfer
+///
+/// `Main` is the spot wallet, `Umfuture` and `Cmfuture` the USD-M and
+/// COIN-M futures wallets.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UniversalTransferType {
+    MainUmfuture,
+    UmfutureMain,
+    MainCmfuture,
+    CmfutureMain,
+    MainMargin,
+    MarginMain,
+    MainFunding,
+    FundingMain,
+    UmfutureMargin,
+    MarginUmfuture,
+    CmfutureMargin,
+    MarginCmfuture,
+    FundingUmfuture,
+    UmfutureFunding,
+    FundingCmfuture,
+    CmfutureFunding,
+    FundingMargin,
+    MarginFunding,
+}
+
+impl std::fmt::Display for UniversalTransferType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MainUmfuture => write!(f, "MAIN_UMFUTURE"),
+            Self::UmfutureMain => write!(f, "UMFUTURE_MAIN"),
+            Self::MainCmfuture => write!(f, "MAIN_CMFUTURE"),
+            Self::CmfutureMain => write!(f, "CMFUTURE_MAIN"),
+            Self::MainMargin => write!(f, "MAIN_MARGIN"),
+            Self::MarginMain => write!(f, "MARGIN_MAIN"),
+            Self::MainFunding => write!(f, "MAIN_FUNDING"),
+            Self::FundingMain => write!(f, "FUNDING_MAIN"),
+            Self::UmfutureMargin => write!(f, "UMFUTURE_MARGIN"),
+            Self::MarginUmfuture => write!(f, "MARGIN_UMFUTURE"),
+            Self::CmfutureMargin => write!(f, "CMFUTURE_MARGIN"),
+            Self::MarginCmfuture => write!(f, "MARGIN_CMFUTURE"),
+            Self::FundingUmfuture => write!(f, "FUNDING_UMFUTURE"),
+            Self::UmfutureFunding => write!(f, "UMFUTURE_FUNDING"),
+            Self::FundingCmfuture => write!(f, "FUNDING_CMFUTURE"),
+            Self::CmfutureFunding => write!(f, "CMFUTURE_FUNDING"),
+            Self::FundingMargin => write!(f, "FUNDING_MARGIN"),
+            Self::MarginFunding => write!(f, "MARGIN_FUNDING"),
+        }
+    }
+}
+
+/// A page of universal transfers
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UniversalTransferHistory {
+    pub total: u64,
+    #[serde(default)]
+    pub rows: Vec<UniversalTransfer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionId {
     pub tran_id: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WithdrawId {
+    pub id: String,
+}
+
+/// A deposit to the account
+///
+/// `status` is 0 while pending, 6 once credited but not yet unlocked and
+/// 1 on success.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin: String,
+    pub network: String,
+    pub status: u8,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+}
+
+/// A withdrawal from the account
+///
+/// `status` is 0 when the email was sent, 2 while awaiting approval, 4
+/// while processing, 6 once completed, and 1, 3 or 5 when canceled,
+/// rejected or failed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: f64,
+    pub coin: String,
+    pub network: String,
+    pub status: u8,
+    pub address: String,
+    #[serde(default)]
+    pub tx_id: String,
+    pub apply_time: String,
+    pub withdraw_order_id: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
@@ -259,6 +388,104 @@ pub struct OrderBook {
     pub asks: Vec<Asks>,
 }
 
+impl OrderBookLevels for OrderBook {
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
+/// Analytics shared by the spot and futures order book snapshots, written
+/// over their bids and asks.
+pub trait OrderBookLevels {
+    /// The bids, best first.
+    fn bids(&self) -> &[Bids];
+
+    /// The asks, best first.
+    fn asks(&self) -> &[Asks];
+
+    /// Size-weighted average of the bid and ask prices over the top `levels`
+    /// of the book.
+    ///
+    /// Returns `None` if `levels` is zero, a side of the book is empty, or
+    /// the levels hold no quantity.
+    #[must_use]
+    fn weighted_mid(&self, levels: usize) -> Option<Decimal> {
+        if levels == 0 || self.bids().is_empty() || self.asks().is_empty() {
+            return None;
+        }
+        let levels = self
+            .bid_levels()
+            .take(levels)
+            .chain(self.ask_levels().take(levels));
+        let (notional, qty) = levels
+            .fold((Decimal::ZERO, Decimal::ZERO), |(notional, qty), level| {
+                (notional + level.price * level.qty, qty + level.qty)
+            });
+        notional.checked_div(qty)
+    }
+
+    /// Microprice of the top of the book:
+    /// `(bid * ask_qty + ask * bid_qty) / (bid_qty + ask_qty)`.
+    ///
+    /// Returns `None` if a side of the book is empty or the best levels hold
+    /// no quantity.
+    #[must_use]
+    fn microprice(&self) -> Option<Decimal> {
+        let bid = self.bids().first()?;
+        let ask = self.asks().first()?;
+        (bid.price * ask.qty + ask.price * bid.qty).checked_div(bid.qty + ask.qty)
+    }
+
+    /// Check the snapshot is well formed before seeding a local book from it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bids are not strictly descending, the asks
+    /// not strictly ascending, or a level has a zero or negative quantity.
+    fn validate(&self) -> crate::errors::Result<()> {
+        validate_side("bid", self.bid_levels(), Ordering::Less)?;
+        validate_side("ask", self.ask_levels(), Ordering::Greater)
+    }
+
+    /// The bids, best first, as side independent levels
+    fn bid_levels(&self) -> Box<dyn Iterator<Item = Level> + '_> {
+        Box::new(self.bids().iter().map(Level::from))
+    }
+
+    /// The asks, best first, as side independent levels
+    fn ask_levels(&self) -> Box<dyn Iterator<Item = Level> + '_> {
+        Box::new(self.asks().iter().map(Level::from))
+    }
+}
+
+fn validate_side(
+    side: &str,
+    levels: impl Iterator<Item = Level>,
+    order: Ordering,
+) -> crate::errors::Result<()> {
+    let mut previous: Option<Decimal> = None;
+    for Level { price, qty } in levels {
+        if qty <= Decimal::ZERO {
+            bail!("Invalid {} quantity {} at price {}", side, qty, price);
+        }
+        if let Some(previous) = previous {
+            match price.cmp(&previous) {
+                Ordering::Equal => bail!("Duplicate {} price {}", side, price),
+                ordering if ordering != order => {
+                    bail!("Unsorted {} price {} after {}", side, price, previous)
+                }
+                _ => {}
+            }
+        }
+        previous = Some(price);
+    }
+    Ok(())
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct RateLimit {
@@ -268,6 +495,44 @@ pub struct RateLimit {
     pub limit: u64,
 }
 
+/// Kind of usage reported by the rate limit response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RateLimitType {
+    /// `X-MBX-USED-WEIGHT-<interval>` headers.
+    RequestWeight,
+    /// `X-MBX-ORDER-COUNT-<interval>` headers.
+    Orders,
+}
+
+/// Window of a rate limit counter, such as `Minute(1)` for the `1M` suffix
+/// of the rate limit response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RateLimitInterval {
+    Second(u32),
+    Minute(u32),
+    Hour(u32),
+    Day(u32),
+}
+
+impl RateLimitInterval {
+    /// Parse a header interval suffix such as `1M`, `1m`, `10S` or `1D`.
+    ///
+    /// The unit letter is case insensitive, `M` being minutes as in the
+    /// headers.
+    #[must_use]
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        let unit = suffix.chars().last()?;
+        let num = suffix[..suffix.len() - unit.len_utf8()].parse().ok()?;
+        match unit.to_ascii_uppercase() {
+            'S' => Some(Self::Second(num)),
+            'M' => Some(Self::Minute(num)),
+            'H' => Some(Self::Hour(num)),
+            'D' => Some(Self::Day(num)),
+            _ => None,
+        }
+    }
+}
+
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "rust_decimal::serde::str")]
@@ -283,6 +548,70 @@ impl Bids {
     }
 }
 
+/// A response that is either a single object or a list of them.
+///
+/// Some endpoints answer a single symbol query with a list, or with a
+/// single object when a list is expected. Deserializing into `OneOrMany`
+/// accepts both shapes, and reports the error of the item type when neither
+/// matches instead of an opaque untagged enum error.
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    /// The single item of the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response is a list of zero or several items.
+    pub fn into_one(self) -> crate::errors::Result<T> {
+        match self {
+            Self::One(item) => Ok(item),
+            Self::Many(mut items) if items.len() == 1 => Ok(items.remove(0)),
+            Self::Many(items) => Err(format!(
+                "Expected a single {}, got a list of {}",
+                short_type_name::<T>(),
+                items.len()
+            )
+            .into()),
+        }
+    }
+
+    /// The items of the response.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            Self::One(item) => vec![item],
+            Self::Many(items) => items,
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for OneOrMany<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let one_or_many = if value.is_array() {
+            serde_json::from_value(value).map(Self::Many)
+        } else {
+            serde_json::from_value(value).map(Self::One)
+        };
+        one_or_many.map_err(|e| {
+            de::Error::custom(format!("invalid {} response: {e}", short_type_name::<T>()))
+        })
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(untagged)]
@@ -317,6 +646,31 @@ pub struct Asks {
     pub qty: Decimal,
 }
 
+/// A price level of either side of an order book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+impl From<&Bids> for Level {
+    fn from(bid: &Bids) -> Self {
+        Self {
+            price: bid.price,
+            qty: bid.qty,
+        }
+    }
+}
+
+impl From<&Asks> for Level {
+    fn from(ask: &Asks) -> Self {
+        Self {
+            price: ask.price,
+            qty: ask.qty,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct KlineSummary {
     pub open_time: i64,
@@ -340,6 +694,46 @@ pub struct KlineSummary {
     pub taker_buy_base_asset_volume: String,
 
     pub taker_buy_quote_asset_volume: String,
+
+    /// `open` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub open_decimal: Decimal,
+
+    /// `high` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub high_decimal: Decimal,
+
+    /// `low` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub low_decimal: Decimal,
+
+    /// `close` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub close_decimal: Decimal,
+
+    /// `volume` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub volume_decimal: Decimal,
+
+    /// `quote_asset_volume` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub quote_asset_volume_decimal: Decimal,
+
+    /// `taker_buy_base_asset_volume` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub taker_buy_base_asset_volume_decimal: Decimal,
+
+    /// `taker_buy_quote_asset_volume` parsed once, when the kline is deserialized.
+    #[cfg(feature = "kline-decimal")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub taker_buy_quote_asset_volume_decimal: Decimal,
 }
 
 /// Part of the Savings API get all coins response
@@ -426,6 +820,63 @@ pub struct DepositAddress {
     pub url: String,
 }
 
+/// Response to the Savings API dust conversion preview request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    pub details: Vec<DustAsset>,
+    #[serde(with = "string_or_float")]
+    pub total_transfer_btc: f64,
+    #[serde(rename = "totalTransferBNB", with = "string_or_float")]
+    pub total_transfer_bnb: f64,
+    #[serde(with = "string_or_float")]
+    pub dribblet_percentage: f64,
+}
+
+/// An asset that can be converted to BNB
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    pub asset: String,
+    pub asset_full_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount_free: f64,
+    #[serde(rename = "toBTC", with = "string_or_float")]
+    pub to_btc: f64,
+    #[serde(rename = "toBNB", with = "string_or_float")]
+    pub to_bnb: f64,
+    #[serde(rename = "toBNBOffExchange", with = "string_or_float")]
+    pub to_bnb_off_exchange: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange: f64,
+}
+
+/// Result of converting assets into BNB
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResult {
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered: f64,
+    pub transfer_result: Vec<DustTransfer>,
+}
+
+/// Conversion of a single asset into BNB
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransfer {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub from_asset: String,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    pub tran_id: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountUpdateEvent {
@@ -571,6 +1022,32 @@ pub struct BookTickerEvent {
     pub best_ask_qty: String,
 }
 
+/// Best bid and ask of a symbol, parsed from a [`BookTickerEvent`]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Quote {
+    pub symbol: String,
+    pub bid: Decimal,
+    pub bid_qty: Decimal,
+    pub ask: Decimal,
+    pub ask_qty: Decimal,
+    pub update_id: u64,
+}
+
+impl TryFrom<&BookTickerEvent> for Quote {
+    type Error = crate::errors::Error;
+
+    fn try_from(event: &BookTickerEvent) -> crate::errors::Result<Self> {
+        Ok(Self {
+            symbol: event.symbol.clone(),
+            bid: Decimal::from_str(&event.best_bid)?,
+            bid_qty: Decimal::from_str(&event.best_bid_qty)?,
+            ask: Decimal::from_str(&event.best_ask)?,
+            ask_qty: Decimal::from_str(&event.best_ask_qty)?,
+            update_id: event.update_id,
+        })
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DayTickerEvent {
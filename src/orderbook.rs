@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use error_chain::bail;
+use rust_decimal::Decimal;
+
+use crate::errors::ErrorKind;
+use crate::errors::Result;
+use crate::model::DepthOrderBookEvent;
+use crate::model::Level;
+use crate::model::OrderBookLevels;
+use crate::spot::model::OrderBook;
+
+/// Order book of a symbol kept in sync from a depth snapshot and the diff
+/// stream, following the sequence documented by Binance.
+///
+/// Diff events received before the first snapshot are buffered, those the
+/// snapshot already contains are dropped, and the others are applied in
+/// order. A gap in the updates clears the book, which buffers the events
+/// again until the next snapshot.
+#[derive(Debug, Clone, Default)]
+pub struct OrderBookManager {
+    last_update_id: Option<u64>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    buffer: Vec<DepthOrderBookEvent>,
+}
+
+impl OrderBookManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the book with `snapshot`, then apply the buffered events
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot is malformed, or an
+    /// `ErrorKind::OrderBookGap` if the buffered events do not follow it.
+    pub fn apply_snapshot(&mut self, snapshot: OrderBook) -> Result<()> {
+        snapshot.validate()?;
+        self.bids = snapshot
+            .bids
+            .iter()
+            .map(|bid| (bid.price, bid.qty))
+            .collect();
+        self.asks = snapshot
+            .asks
+            .iter()
+            .map(|ask| (ask.price, ask.qty))
+            .collect();
+        self.last_update_id = Some(snapshot.last_update_id);
+
+        for event in std::mem::take(&mut self.buffer) {
+            self.apply_diff(&event)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a diff event, or buffer it until a snapshot is set
+    ///
+    /// Events already contained in the book are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorKind::OrderBookGap` if updates are missing between
+    /// the book and the event. The book is cleared, and a new snapshot must
+    /// be applied.
+    pub fn apply_diff(&mut self, event: &DepthOrderBookEvent) -> Result<()> {
+        let Some(last_update_id) = self.last_update_id else {
+            self.buffer.push(event.clone());
+            return Ok(());
+        };
+        if event.final_update_id <= last_update_id {
+            return Ok(());
+        }
+        if event.first_update_id > last_update_id + 1 {
+            self.reset();
+            bail!(ErrorKind::OrderBookGap(
+                last_update_id,
+                event.first_update_id
+            ));
+        }
+
+        update_side(&mut self.bids, event.bids.iter().map(Level::from));
+        update_side(&mut self.asks, event.asks.iter().map(Level::from));
+        self.last_update_id = Some(event.final_update_id);
+        Ok(())
+    }
+
+    /// Update id of the last snapshot or event applied, `None` until a
+    /// snapshot is set
+    #[must_use]
+    pub fn last_update_id(&self) -> Option<u64> {
+        self.last_update_id
+    }
+
+    /// The best bid
+    #[must_use]
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids().next()
+    }
+
+    /// The best ask
+    #[must_use]
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks().next()
+    }
+
+    /// The bids, best first
+    pub fn bids(&self) -> impl Iterator<Item = Level> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(&price, &qty)| Level { price, qty })
+    }
+
+    /// The asks, best first
+    pub fn asks(&self) -> impl Iterator<Item = Level> + '_ {
+        self.asks.iter().map(|(&price, &qty)| Level { price, qty })
+    }
+
+    fn reset(&mut self) {
+        self.last_update_id = None;
+        self.bids.clear();
+        self.asks.clear();
+    }
+}
+
+/// Set the quantity of every updated level, removing the levels whose
+/// quantity dropped to zero.
+fn update_side(side: &mut BTreeMap<Decimal, Decimal>, levels: impl Iterator<Item = Level>) {
+    for Level { price, qty } in levels {
+        if qty.is_zero() {
+            side.remove(&price);
+        } else {
+            side.insert(price, qty);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 
+use error_chain::bail;
+
 use crate::api::Sapi;
 use crate::api::API;
 use crate::client::Client;
@@ -8,9 +10,15 @@ use crate::errors::Result;
 use crate::model::AssetDetail;
 use crate::model::CoinInfo;
 use crate::model::DepositAddress;
+use crate::model::DepositRecord;
+use crate::model::DustAssets;
+use crate::model::DustTransferResult;
 use crate::model::SpotFuturesTransferType;
 use crate::model::TransactionId;
-use crate::util::build_signed_request;
+use crate::model::UniversalTransferHistory;
+use crate::model::UniversalTransferType;
+use crate::model::WithdrawId;
+use crate::model::WithdrawRecord;
 
 #[derive(Clone)]
 pub struct Savings {
@@ -39,7 +47,12 @@ impl Savings {
         config: &Config,
     ) -> Result<Self> {
         Ok(Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())?,
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            )?,
             recv_window: config.recv_window,
         })
     }
@@ -50,7 +63,9 @@ impl Savings {
     ///
     /// Returns an error if the request fails.
     pub async fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
-        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::new(), self.recv_window)?;
         self.client
             .get_signed(API::Savings(Sapi::AllCoins), Some(request))
             .await
@@ -69,7 +84,9 @@ impl Savings {
         if let Some(asset) = asset {
             parameters.insert("asset".into(), asset);
         }
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Savings(Sapi::AssetDetail), Some(request))
             .await
@@ -97,7 +114,9 @@ impl Savings {
         if let Some(network) = network {
             parameters.insert("network".into(), network);
         }
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
             .await
@@ -121,9 +140,251 @@ impl Savings {
         parameters.insert("asset".into(), asset.into());
         parameters.insert("amount".into(), amount.to_string());
         parameters.insert("type".into(), (transfer_type as u8).to_string());
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
             .await
     }
+
+    /// Transfer `amount` of `asset` between two wallets of the account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn universal_transfer<S>(
+        &self,
+        transfer_type: UniversalTransferType,
+        asset: S,
+        amount: f64,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.to_string());
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.to_string());
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+            .await
+    }
+
+    /// Fetch the universal transfers of a type.
+    ///
+    /// Transfers are returned by pages of `size` (10 by default, up to 100),
+    /// `current` being the page number starting at 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn transfer_history(
+        &self,
+        transfer_type: UniversalTransferType,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<UniversalTransferHistory> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.to_string());
+        if let Some(start_time) = start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(current) = current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+            .await
+    }
+
+    /// Withdraw `amount` of `coin` to `address`.
+    ///
+    /// The default network of the coin is used when `network` is `None`.
+    /// `address_tag` is the memo some networks require in addition to the
+    /// address, and `withdraw_order_id` a client id for the withdrawal.
+    /// Returns the id of the withdrawal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn withdraw<S, A>(
+        &self,
+        coin: S,
+        address: A,
+        amount: f64,
+        network: Option<String>,
+        address_tag: Option<String>,
+        withdraw_order_id: Option<String>,
+    ) -> Result<String>
+    where
+        S: Into<String>,
+        A: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("coin".into(), coin.into());
+        parameters.insert("address".into(), address.into());
+        // `Display` of f64 never uses scientific notation.
+        parameters.insert("amount".into(), amount.to_string());
+        if let Some(network) = network {
+            parameters.insert("network".into(), network);
+        }
+        if let Some(address_tag) = address_tag {
+            parameters.insert("addressTag".into(), address_tag);
+        }
+        if let Some(withdraw_order_id) = withdraw_order_id {
+            parameters.insert("withdrawOrderId".into(), withdraw_order_id);
+        }
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let withdrawal: WithdrawId = self
+            .client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+            .await?;
+        Ok(withdrawal.id)
+    }
+
+    /// Fetch deposit history.
+    ///
+    /// Deposits of the last 90 days are returned when no time range is
+    /// given. At most `limit` deposits are returned (1000 by default), after
+    /// skipping `offset` of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn deposit_history(
+        &self,
+        coin: Option<String>,
+        status: Option<u8>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<DepositRecord>> {
+        let parameters = history_parameters(coin, status, start_time, end_time, offset, limit);
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+            .await
+    }
+
+    /// Fetch withdraw history.
+    ///
+    /// Withdrawals of the last 90 days are returned when no time range is
+    /// given. At most `limit` withdrawals are returned (1000 by default),
+    /// after skipping `offset` of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn withdraw_history(
+        &self,
+        coin: Option<String>,
+        status: Option<u8>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<WithdrawRecord>> {
+        let parameters = history_parameters(coin, status, start_time, end_time, offset, limit);
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+            .await
+    }
+
+    /// Preview the assets that can be converted into BNB.
+    ///
+    /// Returns the BTC and BNB value of every qualifying asset, along with
+    /// the totals a dust conversion would transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn dust_assets(&self) -> Result<DustAssets> {
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::DustBtc), request)
+            .await
+    }
+
+    /// Convert the balances of `assets` into BNB.
+    ///
+    /// The assets that qualify are listed by `dust_assets`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `assets` is empty or the request fails.
+    pub async fn dust_transfer(&self, assets: &[String]) -> Result<DustTransferResult> {
+        if assets.is_empty() {
+            bail!("No asset to convert");
+        }
+        // The endpoint takes one `asset` parameter per asset, which the
+        // parameter map cannot hold.
+        let assets: Vec<String> = assets
+            .iter()
+            .map(|asset| format!("asset={asset}"))
+            .collect();
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .post_signed(
+                API::Savings(Sapi::DustTransfer),
+                format!("{}&{}", assets.join("&"), request),
+            )
+            .await
+    }
+}
+
+fn history_parameters(
+    coin: Option<String>,
+    status: Option<u8>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    offset: Option<u32>,
+    limit: Option<u32>,
+) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    if let Some(coin) = coin {
+        parameters.insert("coin".into(), coin);
+    }
+    if let Some(status) = status {
+        parameters.insert("status".into(), status.to_string());
+    }
+    if let Some(start_time) = start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(offset) = offset {
+        parameters.insert("offset".into(), offset.to_string());
+    }
+    if let Some(limit) = limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+    parameters
 }
@@ -1,11 +1,31 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
 use std::fmt::Display;
+use std::future::Future;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
 
 use error_chain::bail;
+use futures_util::stream;
+use futures_util::StreamExt;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::time::sleep;
 
+use super::general::General;
 use super::model::AccountInformation;
+use super::model::Allocation;
 use super::model::Balance;
+use super::model::CancelReplaceFailure;
+use super::model::CancelReplaceOrder;
+use super::model::CancelReplaceResponse;
+use super::model::OcoOrder;
+use super::model::OcoOrderCanceled;
 use super::model::Order;
+use super::model::OrderAck;
 use super::model::OrderCanceled;
 use super::model::TradeHistory;
 use super::model::Transaction;
@@ -13,10 +33,15 @@ use crate::api::Spot;
 use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::ErrorKind;
 use crate::errors::Result;
+use crate::model::Commission;
 use crate::model::CommissionRates;
 use crate::model::Empty;
-use crate::util::build_signed_request;
+use crate::util::trace_order_canceled;
+use crate::util::trace_order_placed;
+use crate::util::trace_order_rejected;
 
 #[derive(Clone)]
 pub struct Account {
@@ -24,15 +49,128 @@ pub struct Account {
     pub recv_window: u64,
 }
 
-struct OrderRequest {
+/// An order placed with [`Account::place_order`].
+///
+/// [`OrderRequest::new`] sets the required fields, a GTC time in force and
+/// no optional parameter; the others are set with the `set_*` methods:
+///
+///```no_run
+/// use binance::spot::account::*;
+///
+/// let order = OrderRequest::new("LTCBTC", 1.0, 0.1, OrderSide::Buy, OrderType::Limit)
+///     .set_iceberg_qty(0.2)
+///     .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireBoth);
+///```
+pub struct OrderRequest {
     pub symbol: String,
     pub qty: f64,
+    /// Not sent when 0, as for market orders.
     pub price: f64,
     pub stop_price: Option<f64>,
     pub order_side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
+    pub trailing_delta: Option<u64>,
+    /// Only accepted on GTC limit orders.
+    pub iceberg_qty: Option<f64>,
+    /// `Full` by default for market orders, see [`OrderResponseType`].
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl OrderRequest {
+    /// Creates an order of `qty` at `price`, 0 for a market order.
+    #[must_use]
+    pub fn new<S, F>(
+        symbol: S,
+        qty: F,
+        price: f64,
+        order_side: OrderSide,
+        order_type: OrderType,
+    ) -> Self
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        Self {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side,
+            order_type,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    /// Sets the stop price of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_stop_price(mut self, stop_price: f64) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Sets the time in force of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    /// Sets the client order id of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, new_client_order_id: S) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    /// Sets the trailing delta, in basis points, of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    /// Sets the iceberg quantity of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_iceberg_qty(mut self, iceberg_qty: f64) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    /// Sets the response type of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    /// Sets the self-trade prevention mode of this [`OrderRequest`].
+    #[must_use]
+    pub fn set_self_trade_prevention_mode(
+        mut self,
+        self_trade_prevention_mode: SelfTradePreventionMode,
+    ) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode);
+        self
+    }
+}
+
+struct OcoOrderRequest {
+    pub symbol: String,
+    pub order_side: OrderSide,
+    pub qty: f64,
+    pub price: f64,
+    pub stop_price: f64,
+    pub stop_limit_price: f64,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub list_client_order_id: Option<String>,
 }
 
 struct OrderQuoteQuantityRequest {
@@ -43,24 +181,84 @@ struct OrderQuoteQuantityRequest {
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
 }
 
+/// An order type, as sent with an order request, listed in the exchange
+/// information of a symbol or returned with an order
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum OrderType {
     Limit,
+    /// Post-only limit order, rejected if it would take liquidity. It is the
+    /// spot equivalent of the futures `GTX` time in force and takes no time
+    /// in force.
+    LimitMaker,
     Market,
+    StopLoss,
     StopLossLimit,
+    TakeProfit,
+    TakeProfitLimit,
+    /// An order type this crate does not know yet, such as `OTO`.
+    Other(String),
 }
 
-impl Display for OrderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl OrderType {
+    /// The order type as sent by Binance, such as `LIMIT_MAKER`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
         match self {
-            Self::Limit => write!(f, "LIMIT"),
-            Self::Market => write!(f, "MARKET"),
-            Self::StopLossLimit => write!(f, "STOP_LOSS_LIMIT"),
+            Self::Limit => "LIMIT",
+            Self::LimitMaker => "LIMIT_MAKER",
+            Self::Market => "MARKET",
+            Self::StopLoss => "STOP_LOSS",
+            Self::StopLossLimit => "STOP_LOSS_LIMIT",
+            Self::TakeProfit => "TAKE_PROFIT",
+            Self::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+            Self::Other(order_type) => order_type,
+        }
+    }
+}
+
+impl From<&str> for OrderType {
+    fn from(order_type: &str) -> Self {
+        match order_type {
+            "LIMIT" => Self::Limit,
+            "LIMIT_MAKER" => Self::LimitMaker,
+            "MARKET" => Self::Market,
+            "STOP_LOSS" => Self::StopLoss,
+            "STOP_LOSS_LIMIT" => Self::StopLossLimit,
+            "TAKE_PROFIT" => Self::TakeProfit,
+            "TAKE_PROFIT_LIMIT" => Self::TakeProfitLimit,
+            other => Self::Other(other.into()),
+        }
+    }
+}
+
+impl From<String> for OrderType {
+    fn from(order_type: String) -> Self {
+        Self::from(order_type.as_str())
+    }
+}
+
+impl From<OrderType> for String {
+    fn from(order_type: OrderType) -> Self {
+        match order_type {
+            OrderType::Other(order_type) => order_type,
+            order_type => order_type.as_str().into(),
         }
     }
 }
 
+impl Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
 pub enum OrderSide {
     Buy,
     Sell,
@@ -75,6 +273,72 @@ impl Display for OrderSide {
     }
 }
 
+/// How much of an order is returned when it is placed.
+///
+/// `Ack` only acknowledges the order, `Result` adds its state once placed,
+/// and `Full` also lists the trades it was filled with in
+/// `Transaction::fills`. An `Ack` does not make a [`Transaction`], those
+/// orders are placed with [`Account::place_order_ack`].
+pub enum OrderResponseType {
+    Ack,
+    Result,
+    Full,
+}
+
+impl Display for OrderResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ack => write!(f, "ACK"),
+            Self::Result => write!(f, "RESULT"),
+            Self::Full => write!(f, "FULL"),
+        }
+    }
+}
+
+/// What happens to the new order of a cancel-replace when the cancellation
+/// fails.
+pub enum CancelReplaceMode {
+    /// The new order is not placed.
+    StopOnFailure,
+    /// The new order is placed regardless.
+    AllowFailure,
+}
+
+impl Display for CancelReplaceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StopOnFailure => write!(f, "STOP_ON_FAILURE"),
+            Self::AllowFailure => write!(f, "ALLOW_FAILURE"),
+        }
+    }
+}
+
+/// Which orders expire when an order would trade against another order of
+/// the same account.
+///
+/// When no mode is sent, the default mode of the symbol applies.
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+}
+
+impl Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::ExpireTaker => write!(f, "EXPIRE_TAKER"),
+            Self::ExpireMaker => write!(f, "EXPIRE_MAKER"),
+            Self::ExpireBoth => write!(f, "EXPIRE_BOTH"),
+        }
+    }
+}
+
+/// How long a spot order stays active.
+///
+/// Spot has no post-only time in force like the futures `GTX`, place a
+/// [`OrderType::LimitMaker`] order instead.
 #[allow(clippy::all)]
 pub enum TimeInForce {
     GTC,
@@ -117,17 +381,58 @@ impl Account {
         config: &Config,
     ) -> Result<Self> {
         Ok(Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())?,
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            )?,
             recv_window: config.recv_window,
         })
     }
+
+    /// A copy of this account using another recv window, in milliseconds.
+    ///
+    /// The copy shares the client, its connection pool and rate limit
+    /// usage, so it is cheap to create for a single call:
+    /// `account.with_recv_window(10_000).get_account()`. A window above
+    /// [`MAX_RECV_WINDOW`](crate::config::MAX_RECV_WINDOW) makes the signed
+    /// requests of the copy fail.
+    #[must_use]
+    pub fn with_recv_window(&self, recv_window: u64) -> Self {
+        Self {
+            client: self.client.clone(),
+            recv_window,
+        }
+    }
+
     /// Retrieves the account information.
     ///
     /// # Errors
     ///
     /// Returns an error if the account information cannot be retrieved.
     pub async fn get_account(&self) -> Result<AccountInformation> {
-        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Account), Some(request))
+            .await
+    }
+
+    /// Retrieves the account information, without the balances of the
+    /// assets that are neither free nor locked when `omit_zero` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account information cannot be retrieved.
+    pub async fn get_account_filtered(&self, omit_zero: bool) -> Result<AccountInformation> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("omitZeroBalances".into(), omit_zero.to_string());
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::Account), Some(request))
             .await
@@ -144,12 +449,80 @@ impl Account {
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::AccountCommission), Some(request))
             .await
     }
 
+    /// The fee paid for the executed part of a placed order
+    ///
+    /// When the transaction reports its fills, the fee is the sum of their
+    /// commissions, in their commission asset. Otherwise it is computed in
+    /// the quote asset from the executed notional and `commission_rates`:
+    /// the maker or taker rate plus the buyer or seller rate, of both the
+    /// standard and the tax commissions. With `bnb_burn`, and when the
+    /// discount is enabled for the account and the symbol, only the
+    /// standard commission is multiplied by the discount.
+    ///
+    /// Quantities executed when an order is placed took liquidity, so they
+    /// pay the taker rate, except for `LIMIT_MAKER` orders which can only
+    /// make liquidity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fills are charged in different assets, or a
+    /// quantity or a rate is not a valid decimal.
+    pub fn effective_fee(
+        transaction: &Transaction,
+        commission_rates: &CommissionRates,
+        bnb_burn: bool,
+    ) -> Result<Decimal> {
+        if let Some(fills) = transaction
+            .fills
+            .as_deref()
+            .filter(|fills| !fills.is_empty())
+        {
+            let mut fee = Decimal::ZERO;
+            for fill in fills {
+                if fill.commission_asset != fills[0].commission_asset {
+                    bail!(
+                        "Fills charged in both {} and {}",
+                        fills[0].commission_asset,
+                        fill.commission_asset
+                    );
+                }
+                fee += Decimal::try_from(fill.commission)?;
+            }
+            return Ok(fee);
+        }
+
+        let maker = transaction.type_name == OrderType::LimitMaker;
+        let buyer = transaction.side == OrderSide::Buy;
+        let rate = |commission: &Commission| -> Result<Decimal> {
+            let liquidity = if maker {
+                &commission.maker
+            } else {
+                &commission.taker
+            };
+            let side = if buyer {
+                &commission.buyer
+            } else {
+                &commission.seller
+            };
+            Ok(Decimal::from_str(liquidity)? + Decimal::from_str(side)?)
+        };
+        let mut standard = rate(&commission_rates.standard_commission)?;
+        let discount = &commission_rates.discount;
+        if bnb_burn && discount.enabled_for_account && discount.enabled_for_symbol {
+            standard *= Decimal::from_str(&discount.discount)?;
+        }
+        let notional = Decimal::try_from(transaction.cummulative_quote_qty)?;
+        Ok(notional * (standard + rate(&commission_rates.tax_commission)?))
+    }
+
     // Balance for a single Asset
     /// Retrieves the balance for a single asset.
     ///
@@ -174,6 +547,41 @@ impl Account {
         }
     }
 
+    /// Computes the largest quantity of `symbol` that can be bought at
+    /// `price` with the free balance of the quote asset.
+    ///
+    /// The symbol filters come from the exchange information cached in
+    /// `general`, see [`Symbol::max_buy_qty`](super::model::Symbol::max_buy_qty)
+    /// for the rules applied. Set `market` for a market order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the account or the symbol information cannot be
+    /// retrieved, or if the quantity cannot be computed.
+    pub async fn max_buy_qty<S>(
+        &self,
+        symbol: S,
+        price: Decimal,
+        market: bool,
+        general: &mut General,
+    ) -> Result<Decimal>
+    where
+        S: Into<String>,
+    {
+        let symbol = general.get_symbol_info(symbol)?;
+        let account = self.get_account().await?;
+        let mut quote_free = Decimal::ZERO;
+        let mut base_held = Decimal::ZERO;
+        for balance in &account.balances {
+            if balance.asset == symbol.quote_asset {
+                quote_free = balance.free_decimal()?;
+            } else if balance.asset == symbol.base_asset {
+                base_held = balance.free_decimal()? + balance.locked_decimal()?;
+            }
+        }
+        symbol.max_buy_qty(quote_free, base_held, price, market)
+    }
+
     /// Retrieves the current open orders for a single symbol.
     ///
     /// # Errors
@@ -186,12 +594,62 @@ impl Account {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::OpenOrders), Some(request))
             .await
     }
 
+    /// Retrieves the orders of a symbol, open, filled or canceled.
+    ///
+    /// At most `limit` orders are returned (500 by default, up to 1000),
+    /// from `order_id` onwards when it is set, otherwise the most recent
+    /// ones. Page through the history by passing the id following the last
+    /// order received as the next `order_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the orders cannot be retrieved.
+    pub async fn get_all_orders<S, O, T, E, L>(
+        &self,
+        symbol: S,
+        order_id: O,
+        start_time: T,
+        end_time: E,
+        limit: L,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        O: Into<Option<u64>>,
+        T: Into<Option<u64>>,
+        E: Into<Option<u64>>,
+        L: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrders), Some(request))
+            .await
+    }
+
     /// Retrieves all open orders.
     ///
     /// # Errors
@@ -200,7 +658,9 @@ impl Account {
     pub async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
         let parameters: BTreeMap<String, String> = BTreeMap::new();
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::OpenOrders), Some(request))
             .await
@@ -217,10 +677,46 @@ impl Account {
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        let request = build_signed_request(parameters, self.recv_window)?;
-        self.client
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let canceled: Vec<OrderCanceled> = self
+            .client
             .delete_signed(API::Spot(Spot::OpenOrders), Some(request))
-            .await
+            .await?;
+        canceled.iter().for_each(trace_canceled);
+        Ok(canceled)
+    }
+
+    /// Cancels all open orders of every symbol.
+    ///
+    /// The open orders are grouped by symbol and each symbol is canceled
+    /// with `cancel_all_open_orders`, running at most `concurrency` requests
+    /// at a time. The result of every symbol is returned, sorted by symbol,
+    /// so a failure on one symbol does not hide the others.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the open orders cannot be retrieved.
+    pub async fn cancel_all_orders_global(
+        &self,
+        concurrency: usize,
+    ) -> Result<Vec<(String, Result<Vec<OrderCanceled>>)>> {
+        let symbols: BTreeSet<String> = self
+            .get_all_open_orders()
+            .await?
+            .into_iter()
+            .map(|order| order.symbol)
+            .collect();
+
+        Ok(stream::iter(symbols)
+            .map(|symbol| async move {
+                let canceled = self.cancel_all_open_orders(symbol.as_str()).await;
+                (symbol, canceled)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await)
     }
 
     /// Retrieves the status of an order.
@@ -236,12 +732,104 @@ impl Account {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("orderId".into(), order_id.to_string());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    /// Retrieves the status of an order based on its client order id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order status cannot be retrieved.
+    pub async fn order_status_with_client_id<S>(
+        &self,
+        symbol: S,
+        orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::Order), Some(request))
             .await
     }
 
+    /// Place an order and wait until it reaches a terminal status.
+    ///
+    /// `order` is any of the order placement calls of this `Account`. Once
+    /// placed, the order status is polled every `poll_interval` until it is
+    /// `FILLED`, `CANCELED`, `REJECTED` or `EXPIRED`, and the final order is
+    /// returned. An order placed in a terminal status, such as a filled
+    /// market order, is returned without polling.
+    ///
+    /// `timeout` bounds the whole call, placement included. Reaching it does
+    /// not cancel the order, which may still be live on the exchange.
+    ///
+    /// The user data stream reports order updates with a lower latency, and
+    /// should be preferred when already connected.
+    ///
+    ///```no_run
+    /// use std::time::Duration;
+    ///
+    /// use binance::spot::account::*;
+    ///
+    /// async fn run() {
+    ///     let account = Account::new(Some("api_key".into()), Some("secret_key".into())).unwrap();
+    ///     let order = account
+    ///         .place_and_wait(
+    ///             account.limit_buy("LTCBTC", 1, 0.1),
+    ///             Duration::from_millis(500),
+    ///             Duration::from_secs(30),
+    ///         )
+    ///         .await;
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be placed or its status cannot
+    /// be retrieved. Returns an `ErrorKind::OrderTimeout` if the order is not
+    /// terminal before `timeout` elapses, holding its last known state, or
+    /// `None` when the placement itself was not answered in time.
+    pub async fn place_and_wait<F>(
+        &self,
+        order: F,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Order>
+    where
+        F: Future<Output = Result<Transaction>>,
+    {
+        let deadline = Instant::now() + timeout;
+        let transaction = tokio::time::timeout(timeout, order)
+            .await
+            .map_err(|_| ErrorKind::OrderTimeout(None, timeout))??;
+        let mut order = Order::from(transaction);
+        loop {
+            if order.is_terminal() {
+                return Ok(order);
+            }
+            if Instant::now() + poll_interval > deadline {
+                bail!(ErrorKind::OrderTimeout(Some(Box::new(order)), timeout));
+            }
+            sleep(poll_interval).await;
+            order = self
+                .order_status(order.symbol.as_str(), order.order_id)
+                .await?;
+        }
+    }
+
     /// Place a test status order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching
@@ -258,7 +846,9 @@ impl Account {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("orderId".into(), order_id.to_string());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
             .await
@@ -284,28 +874,40 @@ impl Account {
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
     }
 
-    /// Place a test limit buy order.
+    /// Place a limit buy order that fails once `timeout` elapses.
     ///
-    /// This order is sandboxed: it is validated, but not sent to the matching
-    /// engine.
+    /// The timeout applies to this request only, independently of the
+    /// client's other requests. A timed out order may still have been
+    /// placed: look it up by `client_order_id`, with
+    /// [`Account::order_status_with_client_id`], before resubmitting.
     ///
     /// # Errors
     ///
-    /// Returns an error if the test limit buy order cannot be placed.
-    pub async fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    /// Returns an error if the limit buy order cannot be placed before the
+    /// timeout.
+    pub async fn limit_buy_with_timeout<S, F, C>(
+        &self,
+        symbol: S,
+        qty: F,
+        price: f64,
+        client_order_id: C,
+        timeout: Duration,
+    ) -> Result<Transaction>
     where
         S: Into<String>,
         F: Into<f64>,
+        C: Into<String>,
     {
-        let buy = OrderRequest {
+        let order = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
             price,
@@ -313,99 +915,347 @@ impl Account {
             order_side: OrderSide::Buy,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
-            new_client_order_id: None,
+            new_client_order_id: Some(client_order_id.into()),
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
-        let order = build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await
-            .map(|_| ())
+        let order = build_order(order);
+        self.post_order(order, Some(timeout)).await
     }
 
-    /// Place a limit sell order.
+    /// Place a limit maker buy order.
+    ///
+    /// The order is rejected instead of taking liquidity, see
+    /// [`OrderType::LimitMaker`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the limit sell order cannot be placed.
-    pub async fn limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    /// Returns an error if the limit maker buy order cannot be placed.
+    pub async fn limit_maker_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
     where
         S: Into<String>,
         F: Into<f64>,
     {
-        let order = OrderRequest {
+        let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
             price,
             stop_price: None,
-            order_side: OrderSide::Sell,
-            order_type: OrderType::Limit,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::LimitMaker,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
-        let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        let order = build_order(buy);
+        self.post_order(order, None).await
     }
 
-    /// Place a test limit sell order.
+    /// Place a test limit buy order.
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching
     /// engine.
     ///
     /// # Errors
     ///
-    /// Returns an error if the test limit sell order cannot be placed.
-    pub async fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    /// Returns an error if the test limit buy order cannot be placed.
+    pub async fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
     where
         S: Into<String>,
         F: Into<f64>,
     {
-        let order = OrderRequest {
+        let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
             price,
             stop_price: None,
-            order_side: OrderSide::Sell,
+            order_side: OrderSide::Buy,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
-        let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
+        let order = build_order(buy);
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
             .map(|_| ())
     }
 
-    /// Place a market buy order.
+    /// Place a limit sell order.
     ///
     /// # Errors
     ///
-    /// Returns an error if the market buy order cannot be placed.
-    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    /// Returns an error if the limit sell order cannot be placed.
+    pub async fn limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
     where
         S: Into<String>,
         F: Into<f64>,
     {
-        let buy = OrderRequest {
+        let order = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price,
             stop_price: None,
-            order_side: OrderSide::Buy,
-            order_type: OrderType::Market,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(order);
+        self.post_order(order, None).await
+    }
+
+    /// Place a limit sell order that fails once `timeout` elapses, see
+    /// [`Account::limit_buy_with_timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the limit sell order cannot be placed before the
+    /// timeout.
+    pub async fn limit_sell_with_timeout<S, F, C>(
+        &self,
+        symbol: S,
+        qty: F,
+        price: f64,
+        client_order_id: C,
+        timeout: Duration,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+        C: Into<String>,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: Some(client_order_id.into()),
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(order);
+        self.post_order(order, Some(timeout)).await
+    }
+
+    /// Place a limit maker sell order.
+    ///
+    /// The order is rejected instead of taking liquidity, see
+    /// [`OrderType::LimitMaker`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the limit maker sell order cannot be placed.
+    pub async fn limit_maker_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::LimitMaker,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(sell);
+        self.post_order(order, None).await
+    }
+
+    /// Place an iceberg limit buy order, showing only `iceberg_qty` of the
+    /// quantity in the order book at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the iceberg limit buy order cannot be placed.
+    pub async fn iceberg_limit_buy<S, F>(
+        &self,
+        symbol: S,
+        qty: F,
+        price: f64,
+        iceberg_qty: f64,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: Some(iceberg_qty),
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
+        self.post_order(order, None).await
+    }
+
+    /// Place an iceberg limit sell order, showing only `iceberg_qty` of the
+    /// quantity in the order book at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the iceberg limit sell order cannot be placed.
+    pub async fn iceberg_limit_sell<S, F>(
+        &self,
+        symbol: S,
+        qty: F,
+        price: f64,
+        iceberg_qty: f64,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: Some(iceberg_qty),
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(sell);
+        self.post_order(order, None).await
+    }
+
+    /// Place a test limit sell order.
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching
+    /// engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the test limit sell order cannot be placed.
+    pub async fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(order);
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Spot(Spot::Order), request)
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
+            .map(|_| ())
+    }
+
+    /// Place a market buy order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market buy order cannot be placed.
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: 0.0,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(buy);
+        self.post_order(order, None).await
+    }
+
+    /// Place a market buy order that fails once `timeout` elapses, see
+    /// [`Account::limit_buy_with_timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market buy order cannot be placed before the
+    /// timeout.
+    pub async fn market_buy_with_timeout<S, F, C>(
+        &self,
+        symbol: S,
+        qty: F,
+        client_order_id: C,
+        timeout: Duration,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+        C: Into<String>,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: 0.0,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: Some(client_order_id.into()),
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(order);
+        self.post_order(order, Some(timeout)).await
     }
 
     /// Place a test market buy order.
@@ -430,9 +1280,13 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
@@ -462,12 +1316,11 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_quote_quantity_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
     }
 
     /// Place a test MARKET order with quote quantity - BUY
@@ -496,9 +1349,11 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_quote_quantity_order(buy);
-        let request = build_signed_request(order, self.recv_window)?;
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
@@ -524,12 +1379,50 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
+    }
+
+    /// Place a market sell order that fails once `timeout` elapses, see
+    /// [`Account::limit_buy_with_timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the market sell order cannot be placed before the
+    /// timeout.
+    pub async fn market_sell_with_timeout<S, F, C>(
+        &self,
+        symbol: S,
+        qty: F,
+        client_order_id: C,
+        timeout: Duration,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+        C: Into<String>,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: 0.0,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: Some(client_order_id.into()),
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let order = build_order(order);
+        self.post_order(order, Some(timeout)).await
     }
 
     /// Place a test MARKET order - SELL
@@ -554,9 +1447,13 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
@@ -589,12 +1486,11 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_quote_quantity_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
     }
 
     /// Place a test MARKET order with quote quantity - SELL
@@ -623,9 +1519,11 @@ impl Account {
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_quote_quantity_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
@@ -671,12 +1569,13 @@ impl Account {
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
     }
 
     /// Create a stop limit buy test order for the given symbol, price and stop
@@ -721,9 +1620,13 @@ impl Account {
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
@@ -769,12 +1672,13 @@ impl Account {
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
     }
 
     /// Create a stop limit sell order for the given symbol, price and stop
@@ -819,63 +1723,88 @@ impl Account {
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
+        let request = self.client.build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
             .await
             .map(|_| ())
     }
 
-    /// Place a custom order
+    /// Create a trailing stop sell order for the given symbol, limit price
+    /// and trailing delta. Returning a `Transaction` value with the same
+    /// parameters sent on the order.
+    ///
+    /// The stop trails the highest price reached by `trailing_delta` basis
+    /// points and places a limit order at `price` once triggered. Without an
+    /// `activation_price` the order is a `STOP_LOSS_LIMIT` that starts
+    /// trailing immediately; with one it is a `TAKE_PROFIT_LIMIT` that only
+    /// starts trailing when the market reaches the activation price.
+    ///
+    ///```no_run
+    /// use binance::spot::account::*;
+    ///
+    /// fn main() {
+    ///     let api_key = Some("api_key".into());
+    ///     let secret_key = Some("secret_key".into());
+    ///     let account =  Account::new(api_key, secret_key).unwrap();
+    ///     let result = account.trailing_stop_sell("LTCBTC", 1, 0.09, Some(0.11), 200, TimeInForce::GTC);
+    /// }
+    /// ```
     ///
     /// # Errors
     ///
-    /// Returns an error if the custom order cannot be placed.
-    #[allow(clippy::too_many_arguments)]
-    pub async fn custom_order<S, F>(
+    /// Returns an error if the trailing stop sell order cannot be placed.
+    pub async fn trailing_stop_sell<S, F>(
         &self,
         symbol: S,
         qty: F,
         price: f64,
-        stop_price: Option<f64>,
-        order_side: OrderSide,
-        order_type: OrderType,
+        activation_price: Option<f64>,
+        trailing_delta: u64,
         time_in_force: TimeInForce,
-        new_client_order_id: Option<String>,
     ) -> Result<Transaction>
     where
         S: Into<String>,
         F: Into<f64>,
     {
+        let order_type = if activation_price.is_some() {
+            OrderType::TakeProfitLimit
+        } else {
+            OrderType::StopLossLimit
+        };
         let order = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
             price,
-            stop_price,
-            order_side,
+            stop_price: activation_price,
+            order_side: OrderSide::Sell,
             order_type,
             time_in_force,
-            new_client_order_id,
+            new_client_order_id: None,
+            trailing_delta: Some(trailing_delta),
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
         };
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed(API::Spot(Spot::Order), request)
-            .await
+        self.post_order(order, None).await
     }
 
-    /// Place a test custom order
+    /// Place a custom order
     ///
-    /// This order is sandboxed: it is validated, but not sent to the matching
-    /// engine.
+    /// See [`Account::place_order`] for the optional parameters.
     ///
     /// # Errors
     ///
-    /// Returns an error if the test custom order cannot be placed.
+    /// Returns an error if the custom order cannot be placed.
     #[allow(clippy::too_many_arguments)]
-    pub async fn test_custom_order<S, F>(
+    pub async fn custom_order<S, F>(
         &self,
         symbol: S,
         qty: F,
@@ -885,30 +1814,168 @@ impl Account {
         order_type: OrderType,
         time_in_force: TimeInForce,
         new_client_order_id: Option<String>,
-    ) -> Result<()>
+    ) -> Result<Transaction>
     where
         S: Into<String>,
         F: Into<f64>,
     {
-        let order = OrderRequest {
-            symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price,
-            order_side,
-            order_type,
-            time_in_force,
-            new_client_order_id,
-        };
+        let mut order = OrderRequest::new(symbol, qty, price, order_side, order_type)
+            .set_time_in_force(time_in_force);
+        order.stop_price = stop_price;
+        order.new_client_order_id = new_client_order_id;
+        self.place_order(order).await
+    }
+
+    /// Place an order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order asks for an `Ack` response, which
+    /// [`Account::place_order_ack`] returns, or if it cannot be placed.
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        if matches!(order.new_order_resp_type, Some(OrderResponseType::Ack)) {
+            bail!("An ACK response is not a transaction, use place_order_ack");
+        }
         let order = build_order(order);
-        let request = build_signed_request(order, self.recv_window)?;
-        self.client
-            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
-            .await
-            .map(|_| ())
+        self.post_order(order, None).await
     }
 
-    /// Check an order's status
+    /// Place an order, only waiting for the exchange to acknowledge it
+    ///
+    /// The order is sent with an `Ack` response type, whatever the one of
+    /// `order`. Its status is then followed with [`Account::order_status`]
+    /// or the user data stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be placed.
+    pub async fn place_order_ack(&self, mut order: OrderRequest) -> Result<OrderAck> {
+        order.new_order_resp_type = Some(OrderResponseType::Ack);
+        let order = build_order(order);
+        let request = self
+            .client
+            .build_signed_request(order.clone(), self.recv_window)?;
+        let result = self
+            .client
+            .post_signed::<OrderAck>(API::Spot(Spot::Order), request)
+            .await;
+        match &result {
+            // An acknowledgment carries no status.
+            Ok(ack) => trace_order_placed(&order, &ack.client_order_id, ack.order_id, ""),
+            Err(e) => trace_order_rejected(&order, e),
+        }
+        result
+    }
+
+    /// Place an OCO (one-cancels-the-other) order
+    ///
+    /// A limit maker order at `price` is paired with a stop loss order
+    /// triggered at `stop_price`. The stop loss is a limit order at
+    /// `stop_limit_price`, in force until `stop_limit_time_in_force` (GTC by
+    /// default).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OCO order cannot be placed.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn place_oco_order<S, F>(
+        &self,
+        symbol: S,
+        order_side: OrderSide,
+        qty: F,
+        price: f64,
+        stop_price: f64,
+        stop_limit_price: f64,
+        stop_limit_time_in_force: Option<TimeInForce>,
+        list_client_order_id: Option<String>,
+    ) -> Result<OcoOrder>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let order = OcoOrderRequest {
+            symbol: symbol.into(),
+            order_side,
+            qty: qty.into(),
+            price,
+            stop_price,
+            stop_limit_price,
+            stop_limit_time_in_force,
+            list_client_order_id,
+        };
+        let order = build_oco_order(order);
+        let request = self
+            .client
+            .build_signed_request(order.clone(), self.recv_window)?;
+        let result = self
+            .client
+            .post_signed::<OcoOrder>(API::Spot(Spot::Oco), request)
+            .await;
+        match &result {
+            Ok(oco_order) => {
+                for report in &oco_order.order_reports {
+                    trace_order_placed(
+                        &order,
+                        &report.client_order_id,
+                        report.order_id,
+                        report.status.as_str(),
+                    );
+                }
+            }
+            Err(e) => trace_order_rejected(&order, e),
+        }
+        result
+    }
+
+    /// Place a test custom order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching
+    /// engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the test custom order cannot be placed.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn test_custom_order<S, F>(
+        &self,
+        symbol: S,
+        qty: F,
+        price: f64,
+        stop_price: Option<f64>,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let mut order = OrderRequest::new(symbol, qty, price, order_side, order_type)
+            .set_time_in_force(time_in_force);
+        order.stop_price = stop_price;
+        order.new_client_order_id = new_client_order_id;
+        self.test_order(order).await
+    }
+
+    /// Place a test order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching
+    /// engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the test order cannot be placed.
+    pub async fn test_order(&self, order: OrderRequest) -> Result<()> {
+        let order = build_order(order);
+        let request = self.client.build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Check an order's status
     ///
     /// # Errors
     ///
@@ -921,10 +1988,15 @@ impl Account {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("orderId".into(), order_id.to_string());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        self.client
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let canceled: OrderCanceled = self
+            .client
             .delete_signed(API::Spot(Spot::Order), Some(request))
-            .await
+            .await?;
+        trace_canceled(&canceled);
+        Ok(canceled)
     }
 
     /// Cancel an order based on the original client order id
@@ -944,10 +2016,307 @@ impl Account {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("origClientOrderId".into(), orig_client_order_id);
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        self.client
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let canceled: OrderCanceled = self
+            .client
             .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await?;
+        trace_canceled(&canceled);
+        Ok(canceled)
+    }
+
+    /// Cancel an order and place a new one in a single request
+    ///
+    /// Unlike a cancellation followed by a new order, there is no moment
+    /// without either order resting in the book. With
+    /// [`CancelReplaceMode::StopOnFailure`] the new order is only placed once
+    /// `cancel_order_id` is canceled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be canceled or replaced. When
+    /// only one of them failed, as may happen with
+    /// [`CancelReplaceMode::AllowFailure`], the error is an
+    /// `ErrorKind::CancelReplacePartiallyFailed` holding the outcome of both,
+    /// the new order possibly being placed.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cancel_replace_order<S, F>(
+        &self,
+        symbol: S,
+        cancel_order_id: u64,
+        mode: CancelReplaceMode,
+        qty: F,
+        price: f64,
+        stop_price: Option<f64>,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
+    ) -> Result<CancelReplaceOrder>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let mut order = build_order(order);
+        order.insert("cancelOrderId".into(), cancel_order_id.to_string());
+        self.post_cancel_replace(order, &mode).await
+    }
+
+    /// Cancel an order based on the original client order id and place a
+    /// new one in a single request
+    ///
+    /// See [`Account::cancel_replace_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be canceled or replaced.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cancel_replace_order_with_client_id<S, F>(
+        &self,
+        symbol: S,
+        cancel_orig_client_order_id: String,
+        mode: CancelReplaceMode,
+        qty: F,
+        price: f64,
+        stop_price: Option<f64>,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
+    ) -> Result<CancelReplaceOrder>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        };
+        let mut order = build_order(order);
+        order.insert(
+            "cancelOrigClientOrderId".into(),
+            cancel_orig_client_order_id,
+        );
+        self.post_cancel_replace(order, &mode).await
+    }
+
+    async fn post_cancel_replace(
+        &self,
+        mut order: BTreeMap<String, String>,
+        mode: &CancelReplaceMode,
+    ) -> Result<CancelReplaceOrder> {
+        order.insert("cancelReplaceMode".into(), mode.to_string());
+        let request = self
+            .client
+            .build_signed_request(order.clone(), self.recv_window)?;
+        let result = self
+            .client
+            .post_signed::<CancelReplaceOrder>(API::Spot(Spot::CancelReplace), request)
             .await
+            .map_err(cancel_replace_error);
+        match &result {
+            Ok(replaced) => {
+                trace_canceled(&replaced.cancel_response);
+                let transaction = &replaced.new_order_response;
+                trace_order_placed(
+                    &order,
+                    &transaction.client_order_id,
+                    transaction.order_id,
+                    transaction.status.as_str(),
+                );
+            }
+            Err(e) => match e.kind() {
+                ErrorKind::CancelReplacePartiallyFailed(failure) => {
+                    if let CancelReplaceResponse::Success(canceled) = &failure.cancel_response {
+                        trace_canceled(canceled);
+                    }
+                    match &failure.new_order_response {
+                        CancelReplaceResponse::Success(transaction) => trace_order_placed(
+                            &order,
+                            &transaction.client_order_id,
+                            transaction.order_id,
+                            transaction.status.as_str(),
+                        ),
+                        CancelReplaceResponse::Failure(_) => trace_order_rejected(&order, e),
+                    }
+                }
+                _ => trace_order_rejected(&order, e),
+            },
+        }
+        result
+    }
+
+    /// Retrieves an OCO order list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order list cannot be retrieved.
+    pub async fn get_oco_order(&self, order_list_id: i64) -> Result<OcoOrder> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    /// Retrieves the OCO order lists, optionally from the order list id
+    /// `from_id` or between `start_time` and `end_time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order lists cannot be retrieved.
+    pub async fn get_all_oco_orders<F, S, E, L>(
+        &self,
+        from_id: F,
+        start_time: S,
+        end_time: E,
+        limit: L,
+    ) -> Result<Vec<OcoOrder>>
+    where
+        F: Into<Option<u64>>,
+        S: Into<Option<u64>>,
+        E: Into<Option<u64>>,
+        L: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_id) = from_id.into() {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+            .await
+    }
+
+    /// Retrieves the open OCO order lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the open order lists cannot be retrieved.
+    pub async fn get_open_oco_orders(&self) -> Result<Vec<OcoOrder>> {
+        let request = self
+            .client
+            .build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+            .await
+    }
+
+    /// Cancel both orders of an OCO order list
+    ///
+    /// `new_client_order_id` tags the cancellation, it is generated by
+    /// Binance when not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order list cannot be canceled.
+    pub async fn cancel_oco_order<S>(
+        &self,
+        symbol: S,
+        order_list_id: i64,
+        new_client_order_id: Option<String>,
+    ) -> Result<OcoOrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        self.delete_order_list(parameters, new_client_order_id)
+            .await
+    }
+
+    /// Cancel both orders of an OCO order list based on its client order
+    /// list id
+    ///
+    /// `new_client_order_id` tags the cancellation, it is generated by
+    /// Binance when not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order list cannot be canceled.
+    pub async fn cancel_oco_order_with_client_id<S>(
+        &self,
+        symbol: S,
+        list_client_order_id: String,
+        new_client_order_id: Option<String>,
+    ) -> Result<OcoOrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+        self.delete_order_list(parameters, new_client_order_id)
+            .await
+    }
+
+    async fn delete_order_list(
+        &self,
+        mut parameters: BTreeMap<String, String>,
+        new_client_order_id: Option<String>,
+    ) -> Result<OcoOrderCanceled> {
+        if let Some(new_client_order_id) = new_client_order_id {
+            parameters.insert("newClientOrderId".into(), new_client_order_id);
+        }
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        let canceled: OcoOrderCanceled = self
+            .client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await?;
+        for leg in &canceled.order_reports {
+            trace_order_canceled(
+                &leg.symbol,
+                Some(&leg.orig_client_order_id),
+                Some(leg.order_id),
+                Some(leg.status.as_str()),
+            );
+        }
+        Ok(canceled)
     }
 
     /// Place a test cancel order
@@ -965,7 +2334,9 @@ impl Account {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("orderId".into(), order_id.to_string());
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
             .await
@@ -984,11 +2355,202 @@ impl Account {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
 
-        let request = build_signed_request(parameters, self.recv_window)?;
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+
+    /// Trade history within a time range or from a trade id
+    ///
+    /// At most `limit` trades are returned (500 by default, up to 1000),
+    /// from `from_id` onwards when it is set, otherwise the most recent ones.
+    /// Walk the full history by passing the id following the last trade
+    /// received as the next `from_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trade history cannot be retrieved
+    pub async fn trade_history_with_options<S, T, E, F, O, L>(
+        &self,
+        symbol: S,
+        start_time: T,
+        end_time: E,
+        from_id: F,
+        order_id: O,
+        limit: L,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+        T: Into<Option<u64>>,
+        E: Into<Option<u64>>,
+        F: Into<Option<u64>>,
+        O: Into<Option<u64>>,
+        L: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(from_id) = from_id.into() {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+
+    /// Fills of orders placed through Smart Order Routing (SOR)
+    ///
+    /// SOR fills are reported as allocations rather than trades, so they do
+    /// not show up in the trade history. At most `limit` allocations are
+    /// returned (500 by default, up to 1000).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the allocations cannot be retrieved
+    pub async fn my_allocations<S, T, E, F, O, L>(
+        &self,
+        symbol: S,
+        start_time: T,
+        end_time: E,
+        from_allocation_id: F,
+        order_id: O,
+        limit: L,
+    ) -> Result<Vec<Allocation>>
+    where
+        S: Into<String>,
+        T: Into<Option<u64>>,
+        E: Into<Option<u64>>,
+        F: Into<Option<u64>>,
+        O: Into<Option<u64>>,
+        L: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(from_allocation_id) = from_allocation_id.into() {
+            parameters.insert("fromAllocationId".into(), from_allocation_id.to_string());
+        }
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyAllocations), Some(request))
+            .await
+    }
+
+    /// Trade history of a single order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trade history cannot be retrieved
+    pub async fn trades_for_order<S>(&self, symbol: S, order_id: u64) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = self
+            .client
+            .build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::MyTrades), Some(request))
             .await
     }
+
+    /// Sign and send an order, emitting its lifecycle event.
+    async fn post_order(
+        &self,
+        order: BTreeMap<String, String>,
+        timeout: Option<Duration>,
+    ) -> Result<Transaction> {
+        let request = self
+            .client
+            .build_signed_request(order.clone(), self.recv_window)?;
+        let endpoint = API::Spot(Spot::Order);
+        let result = match timeout {
+            Some(timeout) => {
+                self.client
+                    .post_signed_with_timeout::<Transaction>(endpoint, request, timeout)
+                    .await
+            }
+            None => {
+                self.client
+                    .post_signed::<Transaction>(endpoint, request)
+                    .await
+            }
+        };
+        match &result {
+            Ok(transaction) => trace_order_placed(
+                &order,
+                &transaction.client_order_id,
+                transaction.order_id,
+                transaction.status.as_str(),
+            ),
+            Err(e) => trace_order_rejected(&order, e),
+        }
+        result
+    }
+}
+
+/// Turn the -2021 error of a partially failed cancel-replace into a
+/// `CancelReplacePartiallyFailed` holding the outcome it sent.
+fn cancel_replace_error(error: Error) -> Error {
+    if let ErrorKind::BinanceError(response) = error.kind() {
+        if response.code == -2021 {
+            if let Some(Ok(failure)) = response
+                .data
+                .clone()
+                .map(serde_json::from_value::<CancelReplaceFailure>)
+            {
+                return ErrorKind::CancelReplacePartiallyFailed(Box::new(failure)).into();
+            }
+        }
+    }
+    error
+}
+
+fn trace_canceled(canceled: &OrderCanceled) {
+    trace_order_canceled(
+        &canceled.symbol,
+        canceled
+            .orig_client_order_id
+            .as_deref()
+            .or(canceled.client_order_id.as_deref()),
+        canceled.order_id,
+        None,
+    );
 }
 
 fn build_quote_quantity_order(order: OrderQuoteQuantityRequest) -> BTreeMap<String, String> {
@@ -1008,6 +2570,38 @@ fn build_quote_quantity_order(order: OrderQuoteQuantityRequest) -> BTreeMap<Stri
         order_parameters.insert("newClientOrderId".into(), client_order_id);
     }
 
+    if let Some(resp_type) = new_order_resp_type(order.new_order_resp_type, &order.order_type) {
+        order_parameters.insert("newOrderRespType".into(), resp_type.to_string());
+    }
+
+    if let Some(mode) = order.self_trade_prevention_mode {
+        order_parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+
+    order_parameters
+}
+
+fn build_oco_order(order: OcoOrderRequest) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.order_side.to_string());
+    order_parameters.insert("quantity".into(), order.qty.to_string());
+    order_parameters.insert("price".into(), order.price.to_string());
+    order_parameters.insert("stopPrice".into(), order.stop_price.to_string());
+    order_parameters.insert("stopLimitPrice".into(), order.stop_limit_price.to_string());
+    order_parameters.insert(
+        "stopLimitTimeInForce".into(),
+        order
+            .stop_limit_time_in_force
+            .unwrap_or(TimeInForce::GTC)
+            .to_string(),
+    );
+
+    if let Some(list_client_order_id) = order.list_client_order_id {
+        order_parameters.insert("listClientOrderId".into(), list_client_order_id);
+    }
+
     order_parameters
 }
 
@@ -1025,12 +2619,42 @@ fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
 
     if order.price != 0.0 {
         order_parameters.insert("price".into(), order.price.to_string());
-        order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+        if !matches!(order.order_type, OrderType::LimitMaker) {
+            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+        }
+    }
+
+    if let Some(trailing_delta) = order.trailing_delta {
+        order_parameters.insert("trailingDelta".into(), trailing_delta.to_string());
+    }
+
+    if let Some(iceberg_qty) = order.iceberg_qty {
+        order_parameters.insert("icebergQty".into(), iceberg_qty.to_string());
     }
 
     if let Some(client_order_id) = order.new_client_order_id {
         order_parameters.insert("newClientOrderId".into(), client_order_id);
     }
 
+    if let Some(resp_type) = new_order_resp_type(order.new_order_resp_type, &order.order_type) {
+        order_parameters.insert("newOrderRespType".into(), resp_type.to_string());
+    }
+
+    if let Some(mode) = order.self_trade_prevention_mode {
+        order_parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+
     order_parameters
 }
+
+/// The response type sent with an order, `Full` for market orders by
+/// default so that their fills are returned.
+fn new_order_resp_type(
+    resp_type: Option<OrderResponseType>,
+    order_type: &OrderType,
+) -> Option<OrderResponseType> {
+    resp_type.or(match order_type {
+        OrderType::Market => Some(OrderResponseType::Full),
+        _ => None,
+    })
+}
@@ -1,25 +1,46 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use error_chain::bail;
+use serde::Deserialize;
+use serde::Serialize;
 
+use super::model::ExchangeInfoUpdate;
 use super::model::ExchangeInformation;
 use super::model::ServerTime;
 use super::model::Symbol;
+use super::model::SymbolFilters;
 use crate::api::Spot;
 use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
 use crate::errors::Result;
 use crate::model::Empty;
+use crate::util;
 
 const CACHE_TTL: u64 = 600; // 10 minutes.
 
+/// Exchange information cache as persisted by `General::save_cache`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile<T> {
+    last_update: u64,
+    exchange_info: T,
+}
+
 #[derive(Clone, Debug)]
 pub struct General {
     pub client: Client,
     pub(crate) cache: Option<ExchangeInformation>,
     pub(crate) last_update: Option<u64>,
+    pub(crate) filter_table: Option<HashMap<String, SymbolFilters>>,
+    /// Position of each cached symbol in `cache.symbols`
+    pub(crate) symbol_index: HashMap<String, usize>,
 }
 
 impl General {
@@ -47,9 +68,16 @@ impl General {
         config: &Config,
     ) -> Result<Self> {
         Ok(Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())?,
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            )?,
             cache: None,
             last_update: None,
+            filter_table: None,
+            symbol_index: HashMap::new(),
         })
     }
 
@@ -84,6 +112,23 @@ impl General {
         }
     }
 
+    /// Synchronize the timestamps of the signed requests with the server
+    /// clock
+    ///
+    /// Measures the offset of the server clock over the local clock, which
+    /// the signed requests of this client, and of the other clients built
+    /// from the same `Config`, then add to their timestamps. Returns it in
+    /// milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn sync_time(&self) -> Result<i64> {
+        let sent = SystemTime::now();
+        let server_time: ServerTime = self.client.get(API::Spot(Spot::Time), None).await?;
+        util::sync_time_offset(self.client.time_offset(), sent, server_time.server_time)
+    }
+
     /// Obtain exchange information
     /// - Current exchange trading rules and symbol information
     /// The boolean is true if the cache was used.
@@ -115,14 +160,117 @@ impl General {
     pub async fn update_cache(&mut self) -> Result<()> {
         let info: ExchangeInformation =
             self.client.get(API::Spot(Spot::ExchangeInfo), None).await?;
-        self.cache = Some(info.clone());
+        self.cache = Some(info);
+        self.filter_table = None;
+        self.index_symbols();
+        self.touch();
+        Ok(())
+    }
+
+    /// Update the cache in place, replacing only the symbols that changed
+    ///
+    /// Unchanged symbols keep their cached value, and the filter table is
+    /// only updated for the symbols that were added, changed or removed.
+    /// The cache is filled entirely when it is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time cannot be retrieved.
+    pub async fn update_cache_incremental(&mut self) -> Result<ExchangeInfoUpdate> {
+        let info: ExchangeInformation =
+            self.client.get(API::Spot(Spot::ExchangeInfo), None).await?;
+        let update = self.merge_exchange_info(info);
+        self.touch();
+        Ok(update)
+    }
+
+    fn merge_exchange_info(&mut self, info: ExchangeInformation) -> ExchangeInfoUpdate {
+        let mut update = ExchangeInfoUpdate::default();
+        let Some(cache) = self.cache.as_mut() else {
+            update.added = info.symbols.iter().map(|s| s.symbol.clone()).collect();
+            self.cache = Some(info);
+            self.filter_table = None;
+            self.index_symbols();
+            return update;
+        };
+        cache.timezone = info.timezone;
+        cache.server_time = info.server_time;
+        cache.rate_limits = info.rate_limits;
+
+        let mut fresh = HashSet::with_capacity(info.symbols.len());
+        for symbol in info.symbols {
+            fresh.insert(symbol.symbol.clone());
+            if let Some(&index) = self.symbol_index.get(&symbol.symbol) {
+                if cache.symbols[index] != symbol {
+                    update.changed.push(symbol.symbol.clone());
+                    cache.symbols[index] = symbol;
+                }
+            } else {
+                update.added.push(symbol.symbol.clone());
+                self.symbol_index
+                    .insert(symbol.symbol.clone(), cache.symbols.len());
+                cache.symbols.push(symbol);
+            }
+        }
+        if fresh.len() < cache.symbols.len() {
+            cache.symbols.retain(|symbol| {
+                let listed = fresh.contains(&symbol.symbol);
+                if !listed {
+                    update.removed.push(symbol.symbol.clone());
+                }
+                listed
+            });
+            self.index_symbols();
+        }
+        self.update_filter_table(&update);
+        update
+    }
+
+    fn update_filter_table(&mut self, update: &ExchangeInfoUpdate) {
+        let (Some(table), Some(cache)) = (self.filter_table.as_mut(), self.cache.as_ref()) else {
+            return;
+        };
+        for symbol in &update.removed {
+            table.remove(symbol);
+        }
+        for symbol in update.added.iter().chain(&update.changed) {
+            let index = self.symbol_index[symbol];
+            if let Ok(filters) = SymbolFilters::try_from(&cache.symbols[index]) {
+                table.insert(symbol.clone(), filters);
+            } else {
+                // Rebuilt on next use, which reports the error.
+                self.filter_table = None;
+                return;
+            }
+        }
+    }
+
+    fn index_symbols(&mut self) {
+        self.symbol_index = self
+            .cache
+            .as_ref()
+            .map(|cache| {
+                cache
+                    .symbols
+                    .iter()
+                    .enumerate()
+                    .map(|(index, symbol)| (symbol.symbol.clone(), index))
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+
+    fn touch(&mut self) {
         self.last_update = Some(
             SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
         );
-        Ok(())
     }
 
     /// Check if the cache is still valid
@@ -138,10 +286,59 @@ impl General {
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs()
-                - self.last_update.unwrap()
+                .saturating_sub(self.last_update.unwrap())
                 < CACHE_TTL
     }
 
+    /// Save the cached exchange information to a JSON file
+    ///
+    /// The time of the last update is saved along, so that a cache loaded
+    /// with `load_cache` expires when the original one would have.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache is empty or the file cannot be written.
+    pub fn save_cache<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let (Some(exchange_info), Some(last_update)) = (self.cache.as_ref(), self.last_update)
+        else {
+            bail!("No cache");
+        };
+        let file = CacheFile {
+            last_update,
+            exchange_info,
+        };
+        fs::write(path, serde_json::to_vec(&file)?)?;
+        Ok(())
+    }
+
+    /// Load the exchange information cache from a file written by
+    /// `save_cache`
+    ///
+    /// The loaded cache replaces the current one. Returns whether it is still
+    /// valid: when it is not, `update_cache` should be called before use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a saved cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time cannot be retrieved.
+    pub fn load_cache<P>(&mut self, path: P) -> Result<bool>
+    where
+        P: AsRef<Path>,
+    {
+        let file: CacheFile<ExchangeInformation> = serde_json::from_slice(&fs::read(path)?)?;
+        self.cache = Some(file.exchange_info);
+        self.last_update = Some(file.last_update);
+        self.filter_table = None;
+        self.index_symbols();
+        Ok(self.has_cache())
+    }
+
     /// Get Symbol information
     ///
     /// # Errors
@@ -151,17 +348,71 @@ impl General {
     where
         S: Into<String>,
     {
+        if !self.has_cache() {
+            bail!("No cache");
+        }
         let upper_symbol = symbol.into().to_uppercase();
-        match self.exchange_info() {
-            Ok(info) => {
-                for item in info.0.symbols {
-                    if item.symbol == upper_symbol {
-                        return Ok(item);
-                    }
-                }
-                bail!("Symbol not found")
+        match self.symbol_index.get(&upper_symbol) {
+            Some(&index) => Ok(self.cache.as_ref().unwrap().symbols[index].clone()),
+            None => bail!("Symbol not found"),
+        }
+    }
+
+    /// Get the trading filters of every symbol, keyed by symbol
+    ///
+    /// The table is built from the cached exchange information on first use
+    /// and rebuilt after the next `update_cache`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache is empty or a filter value is not a
+    /// valid decimal.
+    pub fn filter_table(&mut self) -> Result<&HashMap<String, SymbolFilters>> {
+        if !self.has_cache() {
+            bail!("No cache");
+        }
+        if self.filter_table.is_none() {
+            let symbols = &self.cache.as_ref().unwrap().symbols;
+            let mut table = HashMap::with_capacity(symbols.len());
+            for symbol in symbols {
+                table.insert(symbol.symbol.clone(), SymbolFilters::try_from(symbol)?);
             }
-            Err(e) => Err(e),
+            self.filter_table = Some(table);
+        }
+        Ok(self.filter_table.as_ref().unwrap())
+    }
+
+    /// Compare the cached exchange information with a previous snapshot
+    ///
+    /// Returns the symbols listed since `previous`, with their full
+    /// information, and the names of the symbols delisted since.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache is empty.
+    pub fn diff_symbols(
+        &self,
+        previous: &ExchangeInformation,
+    ) -> Result<(Vec<Symbol>, Vec<String>)> {
+        if !self.has_cache() {
+            bail!("No cache");
         }
+        let current = &self.cache.as_ref().unwrap().symbols;
+        let previous_names: HashSet<&str> =
+            previous.symbols.iter().map(|s| s.symbol.as_str()).collect();
+        let current_names: HashSet<&str> = current.iter().map(|s| s.symbol.as_str()).collect();
+
+        let added = current
+            .iter()
+            .filter(|s| !previous_names.contains(s.symbol.as_str()))
+            .cloned()
+            .collect();
+        let removed = previous
+            .symbols
+            .iter()
+            .filter(|s| !current_names.contains(s.symbol.as_str()))
+            .map(|s| s.symbol.clone())
+            .collect();
+        Ok((added, removed))
     }
 }
@@ -1,5 +1,13 @@
 use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::Display;
 
+use error_chain::bail;
+use futures_util::future::try_join;
+use futures_util::stream;
+use futures_util::StreamExt;
+use rust_decimal::Decimal;
 use serde_json::Value;
 
 use super::model::AggTrade;
@@ -10,15 +18,87 @@ use super::model::KlineSummary;
 use super::model::OrderBook;
 use super::model::PriceStats;
 use super::model::Prices;
+use super::model::RollingWindowStats;
 use super::model::SymbolPrice;
 use super::model::Tickers;
+use super::model::Trade;
 use crate::api::Spot;
 use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
 use crate::errors::Result;
+use crate::model::OneOrMany;
+use crate::util::build_array_param;
 use crate::util::build_request;
 
+/// Depth of a spot order book request.
+///
+/// `Custom` passes any other value through, for limits added by Binance
+/// after this release.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpotDepthLimit {
+    Limit5,
+    Limit10,
+    Limit20,
+    Limit50,
+    Limit100,
+    Limit500,
+    Limit1000,
+    Limit5000,
+    Custom(u64),
+}
+
+impl SpotDepthLimit {
+    /// The `limit` query value.
+    #[must_use]
+    pub fn value(self) -> u64 {
+        match self {
+            Self::Limit5 => 5,
+            Self::Limit10 => 10,
+            Self::Limit20 => 20,
+            Self::Limit50 => 50,
+            Self::Limit100 => 100,
+            Self::Limit500 => 500,
+            Self::Limit1000 => 1000,
+            Self::Limit5000 => 5000,
+            Self::Custom(limit) => limit,
+        }
+    }
+}
+
+impl From<u64> for SpotDepthLimit {
+    fn from(limit: u64) -> Self {
+        match limit {
+            5 => Self::Limit5,
+            10 => Self::Limit10,
+            20 => Self::Limit20,
+            50 => Self::Limit50,
+            100 => Self::Limit100,
+            500 => Self::Limit500,
+            1000 => Self::Limit1000,
+            5000 => Self::Limit5000,
+            limit => Self::Custom(limit),
+        }
+    }
+}
+
+/// Fields returned by the ticker statistics.
+///
+/// `Mini` leaves out the price change and the weighted average price.
+pub enum TickerType {
+    Full,
+    Mini,
+}
+
+impl Display for TickerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "FULL"),
+            Self::Mini => write!(f, "MINI"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Market {
     pub client: Client,
@@ -47,7 +127,12 @@ impl Market {
         config: &Config,
     ) -> Result<Self> {
         Ok(Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())?,
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            )?,
             recv_window: config.recv_window,
         })
     }
@@ -68,22 +153,53 @@ impl Market {
     }
 
     /// Order book at a custom depth. Currently supported values
-    /// are 5, 10, 20, 50, 100, 500, 1000 and 5000
+    /// are 5, 10, 20, 50, 100, 500, 1000 and 5000, see [`SpotDepthLimit`]
     ///
     /// # Errors
     ///
     /// Returns an error if the request does not succeed.
-    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    pub async fn get_custom_depth<S, D>(&self, symbol: S, depth: D) -> Result<OrderBook>
     where
         S: Into<String>,
+        D: Into<SpotDepthLimit>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("limit".into(), depth.to_string());
+        parameters.insert("limit".into(), depth.into().value().to_string());
         let request = build_request(parameters);
         self.client.get(API::Spot(Spot::Depth), Some(request)).await
     }
 
+    /// Latest price and order book of several symbols, to seed local state
+    ///
+    /// The price and order book of every symbol are requested together,
+    /// for at most `concurrency` symbols at a time. The result of every
+    /// symbol is returned, so a failure on one symbol does not hide the
+    /// others.
+    pub async fn warm_snapshot<D>(
+        &self,
+        symbols: &[String],
+        depth: D,
+        concurrency: usize,
+    ) -> HashMap<String, Result<(SymbolPrice, OrderBook)>>
+    where
+        D: Into<SpotDepthLimit>,
+    {
+        let depth = depth.into();
+        stream::iter(symbols)
+            .map(|symbol| async move {
+                let snapshot = try_join(
+                    self.get_price(symbol.as_str()),
+                    self.get_custom_depth(symbol.as_str(), depth),
+                )
+                .await;
+                (symbol.clone(), snapshot)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
     /// Latest price for ALL symbols.
     ///
     /// # Errors
@@ -105,6 +221,27 @@ impl Market {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
+        self.client
+            .get::<OneOrMany<SymbolPrice>>(API::Spot(Spot::Price), Some(request))
+            .await?
+            .into_one()
+    }
+
+    /// Latest price for a list of symbols, in one request.
+    ///
+    /// The request weighs 4, as much as four requests for one symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `symbols` is empty or if the request does not
+    /// succeed.
+    pub async fn get_prices(&self, symbols: &[String]) -> Result<Vec<SymbolPrice>> {
+        if symbols.is_empty() {
+            bail!("At least one symbol is required");
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_array_param(symbols)?);
+        let request = build_request(parameters);
         self.client.get(API::Spot(Spot::Price), Some(request)).await
     }
 
@@ -148,8 +285,9 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
         self.client
-            .get(API::Spot(Spot::BookTicker), Some(request))
-            .await
+            .get::<OneOrMany<Tickers>>(API::Spot(Spot::BookTicker), Some(request))
+            .await?
+            .into_one()
     }
 
     /// 24hr ticker price change statistics
@@ -164,6 +302,58 @@ impl Market {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
+        self.client
+            .get::<OneOrMany<PriceStats>>(API::Spot(Spot::Ticker24hr), Some(request))
+            .await?
+            .into_one()
+    }
+
+    /// Price change statistics over a rolling window of `window_size`, such
+    /// as "15m", "4h" or "1d"
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request does not succeed.
+    pub async fn get_rolling_window_stats<S1, S2, T>(
+        &self,
+        symbol: S1,
+        window_size: S2,
+        ticker_type: T,
+    ) -> Result<RollingWindowStats>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        T: Into<Option<TickerType>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("windowSize".into(), window_size.into());
+        if let Some(ticker_type) = ticker_type.into() {
+            parameters.insert("type".into(), ticker_type.to_string());
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::TickerWindow), Some(request))
+            .await
+    }
+
+    /// 24hr ticker price change statistics for a list of symbols, in one
+    /// request
+    ///
+    /// The request weighs 2 for up to 20 symbols, 40 for up to 100 symbols
+    /// and 80 beyond, as much as `get_all_24h_price_stats`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `symbols` is empty or if the request does not
+    /// succeed.
+    pub async fn get_24h_price_stats_multi(&self, symbols: &[String]) -> Result<Vec<PriceStats>> {
+        if symbols.is_empty() {
+            bail!("At least one symbol is required");
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_array_param(symbols)?);
+        let request = build_request(parameters);
         self.client
             .get(API::Spot(Spot::Ticker24hr), Some(request))
             .await
@@ -227,6 +417,66 @@ impl Market {
             .await
     }
 
+    /// Recent trades list
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request does not succeed.
+    pub async fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{lt}"));
+        }
+
+        let request = build_request(parameters);
+
+        self.client
+            .get(API::Spot(Spot::Trades), Some(request))
+            .await
+    }
+
+    /// Old trade lookup, starting at trade `from_id` or at the most recent
+    /// trades
+    ///
+    /// The request is not signed, but requires an API key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request does not succeed.
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self,
+        symbol: S1,
+        limit: S2,
+        from_id: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{lt}"));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{fi}"));
+        }
+
+        let request = build_request(parameters);
+
+        self.client
+            .get_with_api_key(API::Spot(Spot::HistoricalTrades), Some(request))
+            .await
+    }
+
     /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m",
     /// ...) [docs](https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data)
     ///
@@ -278,4 +528,45 @@ impl Market {
 
         Ok(klines)
     }
+
+    /// Ratio of the 24hr volume of `symbol` to its average daily volume over
+    /// the last `lookback_days` completed days.
+    ///
+    /// A ratio above one means the symbol trades more than usual. The
+    /// current day kline, still open, is left out of the average.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lookback_days` is not between 1 and 999, if a
+    /// request does not succeed, or if the symbol has no daily volume to
+    /// compare with.
+    pub async fn relative_volume<S>(&self, symbol: S, lookback_days: u16) -> Result<Decimal>
+    where
+        S: Into<String>,
+    {
+        if !(1..1000).contains(&lookback_days) {
+            bail!(
+                "Lookback must be between 1 and 999 days, got {}",
+                lookback_days
+            );
+        }
+        let symbol = symbol.into();
+        let stats = self.get_24h_price_stats(symbol.as_str()).await?;
+        let KlineSummaries::AllKlineSummaries(mut klines) = self
+            .get_klines(symbol.as_str(), "1d", lookback_days + 1, None, None)
+            .await?;
+        // The last kline is the current, incomplete, day.
+        klines.pop();
+
+        let mut total = Decimal::ZERO;
+        for kline in &klines {
+            total += kline.volume.parse::<Decimal>()?;
+        }
+        if total.is_zero() {
+            bail!("No daily volume for {} to compare with", symbol);
+        }
+        let average = total / Decimal::from(klines.len());
+        let volume = Decimal::try_from(stats.volume)?;
+        Ok((volume / average).normalize())
+    }
 }
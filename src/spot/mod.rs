@@ -2,6 +2,7 @@ pub mod account;
 pub mod general;
 pub mod market;
 pub mod model;
+pub mod stream;
 pub mod user_stream;
 pub mod websockets;
 
@@ -9,5 +10,6 @@ pub use account::Account;
 pub use general::General;
 pub use market::Market;
 pub use user_stream::UserStream;
+pub use user_stream::UserStreamWebSocket;
 pub use websockets::WebSockets;
 pub use websockets::WebsocketEvent;
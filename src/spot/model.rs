@@ -1,10 +1,16 @@
+use std::collections::HashSet;
 use std::convert::TryFrom;
+use std::str::FromStr;
 
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::from_value;
 use serde_json::Value;
 
+use super::account::OrderSide;
+use super::account::OrderType;
+use crate::errors::BinanceContentError;
 use crate::errors::Error;
 use crate::errors::ErrorKind;
 use crate::errors::Result;
@@ -12,9 +18,12 @@ use crate::model::string_or_float;
 pub use crate::model::Asks;
 pub use crate::model::Bids;
 pub use crate::model::BookTickers;
+pub use crate::model::Commission;
 pub use crate::model::Filters;
 pub use crate::model::KlineSummaries;
 pub use crate::model::KlineSummary;
+pub use crate::model::Level;
+pub use crate::model::OrderBookLevels;
 pub use crate::model::RateLimit;
 pub use crate::model::ServerTime;
 pub use crate::model::SymbolInfo;
@@ -30,7 +39,23 @@ pub struct ExchangeInformation {
     pub symbols: Vec<Symbol>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Symbols changed by an incremental exchange information update
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExchangeInfoUpdate {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl ExchangeInfoUpdate {
+    /// Whether no symbol was added, changed or removed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
@@ -46,6 +71,113 @@ pub struct Symbol {
     pub filters: Vec<Filters>,
 }
 
+impl Symbol {
+    /// The largest quantity that can be bought at `price` with `quote_free`
+    /// of the quote asset, while `base_held` of the base asset is already
+    /// held.
+    ///
+    /// The quantity is rounded down to the step of the `LOT_SIZE` filter
+    /// (`MARKET_LOT_SIZE` for a market order, when it defines a step),
+    /// capped by its maximum quantity and by the room left under the
+    /// `MAX_POSITION` filter. `Decimal::ZERO` is returned when the result is
+    /// below the minimum quantity or the minimum notional.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `price` is not positive or a filter value is not
+    /// a valid decimal.
+    pub fn max_buy_qty(
+        &self,
+        quote_free: Decimal,
+        base_held: Decimal,
+        price: Decimal,
+        market: bool,
+    ) -> Result<Decimal> {
+        if price <= Decimal::ZERO {
+            return Err(format!("Price must be positive, got {price}").into());
+        }
+        let Some(mut qty) = quote_free.checked_div(price) else {
+            return Err(format!("Quantity overflows for price {price}").into());
+        };
+        let mut lot_size = None;
+        let mut market_lot_size = None;
+        let mut min_notional = None;
+        for filter in &self.filters {
+            match filter {
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => lot_size = Some((min_qty, max_qty, step_size)),
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => market_lot_size = Some((min_qty, max_qty, step_size)),
+                Filters::MinNotional {
+                    min_notional: Some(notional),
+                    apply_to_market,
+                    ..
+                }
+                | Filters::Notional {
+                    min_notional: Some(notional),
+                    apply_to_market,
+                    ..
+                } if !market || apply_to_market.unwrap_or(true) => {
+                    min_notional = Some(Decimal::from_str(notional)?);
+                }
+                Filters::MaxPosition { max_position } => {
+                    let room = Decimal::from_str(max_position)? - base_held;
+                    qty = qty.min(room.max(Decimal::ZERO));
+                }
+                _ => {}
+            }
+        }
+        if market {
+            // A zero step means the market lot size defers to `LOT_SIZE`.
+            if let Some(filter) = market_lot_size {
+                if Decimal::from_str(filter.2)? > Decimal::ZERO {
+                    lot_size = Some(filter);
+                }
+            }
+        }
+        if let Some((min_qty, max_qty, step_size)) = lot_size {
+            let step_size = Decimal::from_str(step_size)?;
+            let max_qty = Decimal::from_str(max_qty)?;
+            if max_qty > Decimal::ZERO {
+                qty = qty.min(max_qty);
+            }
+            if step_size > Decimal::ZERO {
+                qty = (qty / step_size).floor() * step_size;
+            }
+            if qty < Decimal::from_str(min_qty)? {
+                return Ok(Decimal::ZERO);
+            }
+        }
+        if let Some(min_notional) = min_notional {
+            if qty * price < min_notional {
+                return Ok(Decimal::ZERO);
+            }
+        }
+        Ok(qty.normalize())
+    }
+
+    /// The order types allowed on the symbol.
+    #[must_use]
+    pub fn allowed_order_types(&self) -> HashSet<OrderType> {
+        self.order_types
+            .iter()
+            .map(|order_type| OrderType::from(order_type.as_str()))
+            .collect()
+    }
+
+    /// Whether orders of `order_type` are allowed on the symbol.
+    #[must_use]
+    pub fn supports_order_type(&self, order_type: OrderType) -> bool {
+        self.order_types.contains(&order_type.to_string())
+    }
+}
+
 impl SymbolInfo for Symbol {
     fn ticker(&self) -> &str {
         &self.symbol
@@ -56,6 +188,54 @@ impl SymbolInfo for Symbol {
     }
 }
 
+/// Trading constraints of a symbol, parsed from its exchange filters.
+///
+/// A field is `None` when the symbol has no filter defining it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolFilters {
+    pub tick_size: Option<Decimal>,
+    pub step_size: Option<Decimal>,
+    pub min_notional: Option<Decimal>,
+    pub min_qty: Option<Decimal>,
+    pub max_qty: Option<Decimal>,
+}
+
+impl TryFrom<&Symbol> for SymbolFilters {
+    type Error = Error;
+
+    fn try_from(symbol: &Symbol) -> Result<Self> {
+        let mut filters = Self::default();
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter { tick_size, .. } => {
+                    filters.tick_size = Some(Decimal::from_str(tick_size)?);
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.min_qty = Some(Decimal::from_str(min_qty)?);
+                    filters.max_qty = Some(Decimal::from_str(max_qty)?);
+                    filters.step_size = Some(Decimal::from_str(step_size)?);
+                }
+                Filters::MinNotional {
+                    min_notional: Some(min_notional),
+                    ..
+                }
+                | Filters::Notional {
+                    min_notional: Some(min_notional),
+                    ..
+                } => {
+                    filters.min_notional = Some(Decimal::from_str(min_notional)?);
+                }
+                _ => {}
+            }
+        }
+        Ok(filters)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInformation {
@@ -66,7 +246,106 @@ pub struct AccountInformation {
     pub can_trade: bool,
     pub can_withdraw: bool,
     pub can_deposit: bool,
+    pub commission_rates: Option<Commission>,
+    pub brokered: Option<bool>,
+    pub require_self_trade_prevention: Option<bool>,
+    pub account_type: Option<String>,
+    pub update_time: Option<u64>,
     pub balances: Vec<Balance>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+/// Status of a spot order
+///
+/// Statuses this crate does not know of are kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OrderStatus {
+    New,
+    PendingNew,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+    Other(String),
+}
+
+impl OrderStatus {
+    /// The status as sent by Binance, such as `PARTIALLY_FILLED`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::New => "NEW",
+            Self::PendingNew => "PENDING_NEW",
+            Self::PartiallyFilled => "PARTIALLY_FILLED",
+            Self::Filled => "FILLED",
+            Self::Canceled => "CANCELED",
+            Self::PendingCancel => "PENDING_CANCEL",
+            Self::Rejected => "REJECTED",
+            Self::Expired => "EXPIRED",
+            Self::ExpiredInMatch => "EXPIRED_IN_MATCH",
+            Self::Other(status) => status,
+        }
+    }
+
+    /// Whether an order with this status will not change anymore.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Filled | Self::Canceled | Self::Rejected | Self::Expired | Self::ExpiredInMatch
+        )
+    }
+}
+
+impl From<&str> for OrderStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "NEW" => Self::New,
+            "PENDING_NEW" => Self::PendingNew,
+            "PARTIALLY_FILLED" => Self::PartiallyFilled,
+            "FILLED" => Self::Filled,
+            "CANCELED" => Self::Canceled,
+            "PENDING_CANCEL" => Self::PendingCancel,
+            "REJECTED" => Self::Rejected,
+            "EXPIRED" => Self::Expired,
+            "EXPIRED_IN_MATCH" => Self::ExpiredInMatch,
+            other => Self::Other(other.into()),
+        }
+    }
+}
+
+impl From<String> for OrderStatus {
+    fn from(status: String) -> Self {
+        Self::from(status.as_str())
+    }
+}
+
+impl From<OrderStatus> for String {
+    fn from(status: OrderStatus) -> Self {
+        match status {
+            OrderStatus::Other(status) => status,
+            status => status.as_str().into(),
+        }
+    }
+}
+
+impl std::fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AccountInformation {
+    /// The account type, such as `SPOT`, when the response includes it.
+    #[must_use]
+    pub fn account_type(&self) -> Option<&str> {
+        self.account_type.as_deref()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -77,6 +356,26 @@ pub struct Balance {
     pub locked: String,
 }
 
+impl Balance {
+    /// The free amount as a decimal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the amount is not a valid decimal.
+    pub fn free_decimal(&self) -> Result<Decimal> {
+        Ok(Decimal::from_str(&self.free)?)
+    }
+
+    /// The locked amount as a decimal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the amount is not a valid decimal.
+    pub fn locked_decimal(&self) -> Result<Decimal> {
+        Ok(Decimal::from_str(&self.locked)?)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -89,11 +388,11 @@ pub struct Order {
     pub orig_qty: String,
     pub executed_qty: String,
     pub cummulative_quote_qty: String,
-    pub status: String,
+    pub status: OrderStatus,
     pub time_in_force: String,
     #[serde(rename = "type")]
-    pub type_name: String,
-    pub side: String,
+    pub type_name: OrderType,
+    pub side: OrderSide,
     #[serde(with = "string_or_float")]
     pub stop_price: f64,
     pub iceberg_qty: String,
@@ -103,6 +402,22 @@ pub struct Order {
     pub orig_quote_order_qty: String,
 }
 
+impl Order {
+    /// Whether the order has reached a final status and will not change
+    /// anymore.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        self.status.is_terminal()
+    }
+
+    /// The id of the order list (OCO) the order belongs to, `None` when the
+    /// order is not part of one.
+    #[must_use]
+    pub fn order_list(&self) -> Option<i64> {
+        order_list(Some(self.order_list_id))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderCanceled {
@@ -112,6 +427,35 @@ pub struct OrderCanceled {
     pub client_order_id: Option<String>,
 }
 
+/// Outcome of cancelling an order and placing its replacement
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrder {
+    pub cancel_result: String,
+    pub new_order_result: String,
+    pub cancel_response: OrderCanceled,
+    pub new_order_response: Transaction,
+}
+
+/// Outcome of a cancel-replace that partially failed, with code -2021: the
+/// cancellation or the new order failed while the other succeeded
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceFailure {
+    pub cancel_result: String,
+    pub new_order_result: String,
+    pub cancel_response: CancelReplaceResponse<OrderCanceled>,
+    pub new_order_response: CancelReplaceResponse<Transaction>,
+}
+
+/// One side of a partially failed cancel-replace
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum CancelReplaceResponse<T> {
+    Success(T),
+    Failure(BinanceContentError),
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
@@ -130,14 +474,142 @@ pub struct Transaction {
     pub cummulative_quote_qty: f64,
     #[serde(with = "string_or_float", default = "default_stop_price")]
     pub stop_price: f64,
-    pub status: String,
+    pub status: OrderStatus,
     pub time_in_force: String,
     #[serde(rename = "type")]
-    pub type_name: String,
-    pub side: String,
+    pub type_name: OrderType,
+    pub side: OrderSide,
     pub fills: Option<Vec<FillInfo>>,
 }
 
+impl Transaction {
+    /// The id of the order list (OCO) the order belongs to, `None` when the
+    /// order is not part of one.
+    #[must_use]
+    pub fn order_list(&self) -> Option<i64> {
+        order_list(self.order_list_id)
+    }
+}
+
+/// The order as of its placement. A placement response has no iceberg or
+/// quote order quantity, they are left empty, and the order was last
+/// updated when it was placed.
+impl From<Transaction> for Order {
+    fn from(transaction: Transaction) -> Self {
+        Self {
+            is_working: !transaction.status.is_terminal(),
+            symbol: transaction.symbol,
+            order_id: transaction.order_id,
+            order_list_id: transaction.order_list_id.unwrap_or(-1),
+            client_order_id: transaction.client_order_id,
+            price: transaction.price,
+            orig_qty: transaction.orig_qty.to_string(),
+            executed_qty: transaction.executed_qty.to_string(),
+            cummulative_quote_qty: transaction.cummulative_quote_qty.to_string(),
+            status: transaction.status,
+            time_in_force: transaction.time_in_force,
+            type_name: transaction.type_name,
+            side: transaction.side,
+            stop_price: transaction.stop_price,
+            iceberg_qty: String::new(),
+            time: transaction.transact_time,
+            update_time: transaction.transact_time,
+            orig_quote_order_qty: String::new(),
+        }
+    }
+}
+
+/// An order as acknowledged by an `ACK` response, placed with
+/// `Account::place_order_ack`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAck {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: Option<i64>,
+    pub client_order_id: String,
+    pub transact_time: u64,
+}
+
+impl OrderAck {
+    /// The id of the order list (OCO) the order belongs to, `None` when the
+    /// order is not part of one.
+    #[must_use]
+    pub fn order_list(&self) -> Option<i64> {
+        order_list(self.order_list_id)
+    }
+}
+
+/// An OCO order list
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrder {
+    pub order_list_id: i64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListEntry>,
+    /// Only sent when the order list is placed
+    #[serde(default)]
+    pub order_reports: Vec<Transaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListEntry {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+/// An OCO order list, as canceled by `Account::cancel_oco_order`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderCanceled {
+    pub order_list_id: i64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListEntry>,
+    pub order_reports: Vec<OrderListLegCanceled>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListLegCanceled {
+    pub symbol: String,
+    pub orig_client_order_id: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: f64,
+    pub status: OrderStatus,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: OrderType,
+    pub side: OrderSide,
+}
+
+/// Binance sends `-1` as the order list id of orders outside an order list.
+fn order_list(order_list_id: Option<i64>) -> Option<i64> {
+    order_list_id.filter(|&id| id != -1)
+}
+
 fn default_stop_price() -> f64 {
     0.0
 }
@@ -170,6 +642,16 @@ pub struct OrderBook {
     pub asks: Vec<Asks>,
 }
 
+impl OrderBookLevels for OrderBook {
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserDataStream {
@@ -187,10 +669,14 @@ pub enum Prices {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
     pub price: f64,
+    /// Time of the last trade of the average, in milliseconds.
+    #[serde(default)]
+    pub close_time: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -209,6 +695,27 @@ pub struct TradeHistory {
     pub is_best_match: bool,
 }
 
+/// A fill of an order routed by Smart Order Routing (SOR)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Allocation {
+    pub symbol: String,
+    pub allocation_id: u64,
+    pub allocation_type: String,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    pub commission: String,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
@@ -239,6 +746,39 @@ pub struct PriceStats {
     pub count: u64,
 }
 
+/// Price change statistics over a rolling window.
+///
+/// The price change and weighted average price are only returned for
+/// `TickerType::Full`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowStats {
+    pub symbol: String,
+    #[serde(default)]
+    pub price_change: Option<String>,
+    #[serde(default)]
+    pub price_change_percent: Option<String>,
+    #[serde(default)]
+    pub weighted_avg_price: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AggTrade {
     #[serde(rename = "T")]
@@ -259,6 +799,21 @@ pub struct AggTrade {
     pub qty: f64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderTradeEvent {
@@ -275,10 +830,10 @@ pub struct OrderTradeEvent {
     pub new_client_order_id: String,
 
     #[serde(rename = "S")]
-    pub side: String,
+    pub side: OrderSide,
 
     #[serde(rename = "o")]
-    pub order_type: String,
+    pub order_type: OrderType,
 
     #[serde(rename = "f")]
     pub time_in_force: String,
@@ -305,7 +860,7 @@ pub struct OrderTradeEvent {
     pub execution_type: String,
 
     #[serde(rename = "X")]
-    pub order_status: String,
+    pub order_status: OrderStatus,
 
     #[serde(rename = "r")]
     pub order_reject_reason: String,
@@ -358,26 +913,44 @@ impl TryFrom<&Vec<Value>> for KlineSummary {
     type Error = Error;
 
     fn try_from(row: &Vec<Value>) -> Result<Self> {
+        let open: String = from_value(get_value(row, 1, "open")?)?;
+        let high: String = from_value(get_value(row, 2, "high")?)?;
+        let low: String = from_value(get_value(row, 3, "low")?)?;
+        let close: String = from_value(get_value(row, 4, "close")?)?;
+        let volume: String = from_value(get_value(row, 5, "volume")?)?;
+        let quote_asset_volume: String = from_value(get_value(row, 7, "quote_asset_volume")?)?;
+        let taker_buy_base_asset_volume: String =
+            from_value(get_value(row, 9, "taker_buy_base_asset_volume")?)?;
+        let taker_buy_quote_asset_volume: String =
+            from_value(get_value(row, 10, "taker_buy_quote_asset_volume")?)?;
         Ok(Self {
+            #[cfg(feature = "kline-decimal")]
+            open_decimal: open.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            high_decimal: high.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            low_decimal: low.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            close_decimal: close.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            volume_decimal: volume.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            quote_asset_volume_decimal: quote_asset_volume.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            taker_buy_base_asset_volume_decimal: taker_buy_base_asset_volume.parse()?,
+            #[cfg(feature = "kline-decimal")]
+            taker_buy_quote_asset_volume_decimal: taker_buy_quote_asset_volume.parse()?,
             open_time: from_value(get_value(row, 0, "open_time")?)?,
-            open: from_value(get_value(row, 1, "open")?)?,
-            high: from_value(get_value(row, 2, "high")?)?,
-            low: from_value(get_value(row, 3, "low")?)?,
-            close: from_value(get_value(row, 4, "close")?)?,
-            volume: from_value(get_value(row, 5, "volume")?)?,
+            open,
+            high,
+            low,
+            close,
+            volume,
             close_time: from_value(get_value(row, 6, "close_time")?)?,
-            quote_asset_volume: from_value(get_value(row, 7, "quote_asset_volume")?)?,
+            quote_asset_volume,
             number_of_trades: from_value(get_value(row, 8, "number_of_trades")?)?,
-            taker_buy_base_asset_volume: from_value(get_value(
-                row,
-                9,
-                "taker_buy_base_asset_volume",
-            )?)?,
-            taker_buy_quote_asset_volume: from_value(get_value(
-                row,
-                10,
-                "taker_buy_quote_asset_volume",
-            )?)?,
+            taker_buy_base_asset_volume,
+            taker_buy_quote_asset_volume,
         })
     }
 }
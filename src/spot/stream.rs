@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+/// Interval of the kline streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    OneSecond,
+    OneMinute,
+    ThreeMinutes,
+    FiveMinutes,
+    FifteenMinutes,
+    ThirtyMinutes,
+    OneHour,
+    TwoHours,
+    FourHours,
+    SixHours,
+    EightHours,
+    TwelveHours,
+    OneDay,
+    ThreeDays,
+    OneWeek,
+    OneMonth,
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OneSecond => write!(f, "1s"),
+            Self::OneMinute => write!(f, "1m"),
+            Self::ThreeMinutes => write!(f, "3m"),
+            Self::FiveMinutes => write!(f, "5m"),
+            Self::FifteenMinutes => write!(f, "15m"),
+            Self::ThirtyMinutes => write!(f, "30m"),
+            Self::OneHour => write!(f, "1h"),
+            Self::TwoHours => write!(f, "2h"),
+            Self::FourHours => write!(f, "4h"),
+            Self::SixHours => write!(f, "6h"),
+            Self::EightHours => write!(f, "8h"),
+            Self::TwelveHours => write!(f, "12h"),
+            Self::OneDay => write!(f, "1d"),
+            Self::ThreeDays => write!(f, "3d"),
+            Self::OneWeek => write!(f, "1w"),
+            Self::OneMonth => write!(f, "1M"),
+        }
+    }
+}
+
+/// Number of levels of the partial book depth streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepthLevel {
+    L5,
+    L10,
+    L20,
+}
+
+impl Display for DepthLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::L5 => write!(f, "5"),
+            Self::L10 => write!(f, "10"),
+            Self::L20 => write!(f, "20"),
+        }
+    }
+}
+
+/// Update speed of the depth streams.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateSpeed {
+    Ms100,
+    Ms1000,
+}
+
+impl UpdateSpeed {
+    /// 1000ms is the default speed, left out of the stream names.
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Ms100 => "@100ms",
+            Self::Ms1000 => "",
+        }
+    }
+}
+
+/// Name of a market stream, such as `btcusdt@kline_1m`, to connect or
+/// subscribe to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stream(String);
+
+impl Stream {
+    /// `<symbol>@aggTrade`
+    #[must_use]
+    pub fn agg_trade(symbol: &str) -> Self {
+        Self(format!("{}@aggTrade", symbol.to_lowercase()))
+    }
+
+    /// `<symbol>@trade`
+    #[must_use]
+    pub fn trade(symbol: &str) -> Self {
+        Self(format!("{}@trade", symbol.to_lowercase()))
+    }
+
+    /// `<symbol>@kline_<interval>`
+    #[must_use]
+    pub fn kline(symbol: &str, interval: Interval) -> Self {
+        Self(format!("{}@kline_{}", symbol.to_lowercase(), interval))
+    }
+
+    /// `<symbol>@miniTicker`
+    #[must_use]
+    pub fn mini_ticker(symbol: &str) -> Self {
+        Self(format!("{}@miniTicker", symbol.to_lowercase()))
+    }
+
+    /// `!miniTicker@arr`
+    #[must_use]
+    pub fn all_mini_tickers() -> Self {
+        Self("!miniTicker@arr".into())
+    }
+
+    /// `<symbol>@ticker`
+    #[must_use]
+    pub fn ticker(symbol: &str) -> Self {
+        Self(format!("{}@ticker", symbol.to_lowercase()))
+    }
+
+    /// `!ticker@arr`
+    #[must_use]
+    pub fn all_tickers() -> Self {
+        Self("!ticker@arr".into())
+    }
+
+    /// `<symbol>@bookTicker`
+    #[must_use]
+    pub fn book_ticker(symbol: &str) -> Self {
+        Self(format!("{}@bookTicker", symbol.to_lowercase()))
+    }
+
+    /// Partial book depth, `<symbol>@depth<levels>[@100ms]`
+    #[must_use]
+    pub fn depth(symbol: &str, levels: DepthLevel, speed: UpdateSpeed) -> Self {
+        Self(format!(
+            "{}@depth{}{}",
+            symbol.to_lowercase(),
+            levels,
+            speed.suffix()
+        ))
+    }
+
+    /// Diff depth, `<symbol>@depth[@100ms]`
+    #[must_use]
+    pub fn diff_depth(symbol: &str, speed: UpdateSpeed) -> Self {
+        Self(format!("{}@depth{}", symbol.to_lowercase(), speed.suffix()))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Stream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for Stream {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Stream> for String {
+    fn from(stream: Stream) -> Self {
+        stream.0
+    }
+}
@@ -1,9 +1,17 @@
+use std::time::Duration;
+use std::time::Instant;
+
 use super::model::Success;
 use super::model::UserDataStream;
+use super::websockets::ReconnectEvent;
+use super::websockets::WebSockets;
+use super::websockets::WebsocketEvent;
 use crate::api::Spot;
 use crate::api::API;
 use crate::client::Client;
 use crate::config::Config;
+use crate::errors::Error;
+use crate::errors::ErrorKind;
 use crate::errors::Result;
 
 #[derive(Clone)]
@@ -33,7 +41,12 @@ impl UserStream {
         config: &Config,
     ) -> Result<Self> {
         Ok(Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone())?,
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            )?,
             recv_window: config.recv_window,
         })
     }
@@ -69,3 +82,143 @@ impl UserStream {
             .await
     }
 }
+
+/// Websocket of a user data stream whose listen key is kept alive.
+///
+/// A listen key expires 60 minutes after it was last kept alive. `recv`
+/// keeps it alive every [`UserStreamWebSocket::KEEP_ALIVE_INTERVAL`], and
+/// when it expires anyway, starts a new stream and reconnects to it. The
+/// `WebsocketEvent::ListenKeyExpired` event is still returned, as user data
+/// events may have been missed.
+///
+/// When the connection is closed or fails, `recv` also starts a new stream
+/// and reconnects to it, returning a `WebsocketEvent::Reconnected` event.
+pub struct UserStreamWebSocket {
+    user_stream: UserStream,
+    config: Config,
+    listen_key: String,
+    websocket: WebSockets,
+    keep_alive_interval: Duration,
+    next_keep_alive: Instant,
+}
+
+impl UserStreamWebSocket {
+    /// Half of the 60 minutes validity of a listen key.
+    pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+    /// Start a user data stream and connect to it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream cannot be started or connected to.
+    pub async fn connect(user_stream: UserStream, config: &Config) -> Result<Self> {
+        let listen_key = user_stream.start().await?.listen_key;
+        let websocket = WebSockets::connect_with_config(&listen_key, config).await?;
+        Ok(Self {
+            user_stream,
+            config: config.clone(),
+            listen_key,
+            websocket,
+            keep_alive_interval: Self::KEEP_ALIVE_INTERVAL,
+            next_keep_alive: Instant::now() + Self::KEEP_ALIVE_INTERVAL,
+        })
+    }
+
+    /// Keep the listen key alive every `interval` rather than every
+    /// [`UserStreamWebSocket::KEEP_ALIVE_INTERVAL`].
+    #[must_use]
+    pub fn with_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.keep_alive_interval = interval;
+        self.next_keep_alive = Instant::now() + interval;
+        self
+    }
+
+    /// Listen key of the current stream.
+    #[must_use]
+    pub fn listen_key(&self) -> &str {
+        &self.listen_key
+    }
+
+    /// Receive a message from the user data stream
+    ///
+    /// Returns `None` when the listen key was kept alive before a message
+    /// was received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be parsed or reports an error,
+    /// or if the stream cannot be kept alive or restarted. Only a closed or
+    /// failed connection restarts the stream. A stream that failed to
+    /// restart is restarted again by the next call.
+    pub async fn recv(&mut self) -> Result<Option<WebsocketEvent>> {
+        let until_keep_alive = self
+            .next_keep_alive
+            .saturating_duration_since(Instant::now());
+        match self.websocket.recv_timeout(until_keep_alive).await {
+            Err(e) if matches!(e.kind(), ErrorKind::WebsocketTimeout(_)) => {
+                self.keep_alive().await?;
+                Ok(None)
+            }
+            Ok(Some(WebsocketEvent::ListenKeyExpired(event))) => {
+                self.restart().await?;
+                Ok(Some(WebsocketEvent::ListenKeyExpired(event)))
+            }
+            Err(e) if is_disconnection(&e) => {
+                self.restart().await?;
+                Ok(Some(WebsocketEvent::Reconnected(ReconnectEvent {
+                    attempts: 1,
+                    reason: e.to_string(),
+                })))
+            }
+            result => result,
+        }
+    }
+
+    /// Disconnect from the websocket and close the stream
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the websocket or the stream cannot be closed.
+    pub async fn close(mut self) -> Result<()> {
+        self.websocket.disconnect().await?;
+        self.user_stream.close(&self.listen_key).await?;
+        Ok(())
+    }
+
+    async fn keep_alive(&mut self) -> Result<()> {
+        match self.user_stream.keep_alive(&self.listen_key).await {
+            // -1125: "This listenKey does not exist."
+            Err(e) if matches!(e.kind(), ErrorKind::BinanceError(response) if response.code == -1125) => {
+                self.restart().await
+            }
+            result => {
+                result?;
+                self.next_keep_alive = Instant::now() + self.keep_alive_interval;
+                Ok(())
+            }
+        }
+    }
+
+    async fn restart(&mut self) -> Result<()> {
+        let listen_key = self.user_stream.start().await?.listen_key;
+        let websocket = WebSockets::connect_with_config(&listen_key, &self.config).await?;
+        // The old connection is replaced whether or not it closes cleanly.
+        let _ = self.websocket.disconnect().await;
+        self.listen_key = listen_key;
+        self.websocket = websocket;
+        self.next_keep_alive = Instant::now() + self.keep_alive_interval;
+        Ok(())
+    }
+}
+
+/// Whether `error` is the websocket connection closing or failing, as
+/// opposed to a message that could not be handled.
+fn is_disconnection(error: &Error) -> bool {
+    match error.kind() {
+        ErrorKind::Tungstenite(_) => true,
+        ErrorKind::Msg(msg) => {
+            msg.starts_with("Disconnected ") || msg == "Websocket connection closed"
+        }
+        _ => false,
+    }
+}
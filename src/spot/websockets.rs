@@ -1,11 +1,26 @@
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
 use error_chain::bail;
+use futures_util::stream;
 use futures_util::stream::SplitSink;
 use futures_util::stream::SplitStream;
 use futures_util::SinkExt;
+use futures_util::Stream;
 use futures_util::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tokio::net::TcpStream;
+use tokio::time::sleep;
+pub use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::WebSocketStream;
@@ -15,6 +30,9 @@ use url::Url;
 use super::model::OrderBook;
 use super::model::OrderTradeEvent;
 use crate::config::Config;
+use crate::errors::BinanceContentError;
+use crate::errors::Error;
+use crate::errors::ErrorKind;
 use crate::errors::Result;
 use crate::model::AccountUpdateEvent;
 use crate::model::AggrTradesEvent;
@@ -23,7 +41,9 @@ use crate::model::BookTickerEvent;
 use crate::model::DayTickerEvent;
 use crate::model::DepthOrderBookEvent;
 use crate::model::KlineEvent;
+use crate::model::Quote;
 use crate::model::TradeEvent;
+use crate::model::UserDataStreamExpiredEvent;
 
 #[allow(clippy::all)]
 enum WebsocketAPI {
@@ -52,19 +72,285 @@ pub enum WebsocketEvent {
     OrderTrade(OrderTradeEvent),
     AggrTrades(AggrTradesEvent),
     Trade(TradeEvent),
+    /// Snapshot of the partial book depth streams,
+    /// `<symbol>@depth<levels>[@100ms]`.
     OrderBook(OrderBook),
     DayTicker(DayTickerEvent),
     DayTickerAll(Vec<DayTickerEvent>),
     Kline(KlineEvent),
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
+    /// The connection was lost and has been reestablished, events may have
+    /// been missed in between.
+    Reconnected(ReconnectEvent),
+    /// Answer to a `subscribe`, `unsubscribe` or `list_subscriptions` request.
+    Response(ControlResponse),
+    /// The listen key of the user data stream expired, no more user data
+    /// events will be sent.
+    ListenKeyExpired(UserDataStreamExpiredEvent),
+    /// Payload matching none of the events above, such as the events of a
+    /// stream the crate does not model yet, or the `!miniTicker@arr` array.
+    Unknown(serde_json::Value),
+}
+
+impl WebsocketEvent {
+    /// The `E` event time of the event in milliseconds, `None` for the events
+    /// that do not carry one.
+    #[must_use]
+    pub fn event_time(&self) -> Option<u64> {
+        match self {
+            Self::AccountUpdate(event) => Some(event.event_time),
+            Self::BalanceUpdate(event) => Some(event.event_time),
+            Self::OrderTrade(event) => Some(event.event_time),
+            Self::AggrTrades(event) => Some(event.event_time),
+            Self::Trade(event) => Some(event.event_time),
+            Self::DayTicker(event) => Some(event.event_time),
+            Self::DayTickerAll(events) => events.iter().map(|event| event.event_time).max(),
+            Self::Kline(event) => Some(event.event_time),
+            Self::DepthOrderBook(event) => Some(event.event_time),
+            Self::ListenKeyExpired(event) => Some(event.event_time),
+            Self::Unknown(value) => value.get("E").and_then(serde_json::Value::as_u64),
+            Self::OrderBook(_) | Self::BookTicker(_) | Self::Reconnected(_) | Self::Response(_) => {
+                None
+            }
+        }
+    }
+
+    /// The name of the market stream the event comes from, such as
+    /// `btcusdt@depth`, to route events to their subscription.
+    ///
+    /// Returns `None` for the user data stream events, which come from a
+    /// listen key, for partial book depth snapshots, which do not carry
+    /// their symbol, and for unknown events. Depth updates map to the default `@depth` stream
+    /// whatever their update speed.
+    #[must_use]
+    pub fn stream_key(&self) -> Option<String> {
+        match self {
+            Self::AggrTrades(event) => Some(format!("{}@aggTrade", event.symbol.to_lowercase())),
+            Self::Trade(event) => Some(format!("{}@trade", event.symbol.to_lowercase())),
+            Self::DayTicker(event) => Some(format!("{}@ticker", event.symbol.to_lowercase())),
+            Self::DayTickerAll(_) => Some("!ticker@arr".into()),
+            Self::Kline(event) => Some(format!(
+                "{}@kline_{}",
+                event.symbol.to_lowercase(),
+                event.kline.interval
+            )),
+            Self::DepthOrderBook(event) => Some(format!("{}@depth", event.symbol.to_lowercase())),
+            Self::BookTicker(event) => Some(format!("{}@bookTicker", event.symbol.to_lowercase())),
+            Self::AccountUpdate(_)
+            | Self::BalanceUpdate(_)
+            | Self::OrderTrade(_)
+            | Self::OrderBook(_)
+            | Self::Reconnected(_)
+            | Self::Response(_)
+            | Self::ListenKeyExpired(_)
+            | Self::Unknown(_) => None,
+        }
+    }
+}
+
+/// Reported by a websocket connected with reconnection once it reconnects.
+///
+/// Events sent while the connection was down are lost: a local order book,
+/// for example, has to be synchronized again.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReconnectEvent {
+    /// Number of connection attempts it took to reconnect.
+    pub attempts: u32,
+    /// Why the connection was lost.
+    pub reason: String,
+}
+
+/// Answer to a request sent over the websocket, matched by `id`.
+///
+/// `result` lists the streams for `list_subscriptions`, and is `None` for
+/// the other requests.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ControlResponse {
+    pub id: u64,
+    pub result: Option<Vec<String>>,
+}
+
+/// Backoff between the attempts to reconnect a websocket.
+///
+/// The `n`th attempt waits `base * 2^(n - 1)`, capped at `max`, plus a
+/// random share of up to `jitter` of that delay so that many clients do not
+/// reconnect in lockstep.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    pub base: Duration,
+    pub max: Duration,
+    /// Between 0 and 1.
+    pub jitter: f64,
+    /// Attempts before giving up, `None` to retry forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_millis(500),
+            max: Duration::from_secs(30),
+            jitter: 0.2,
+            max_attempts: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before the `attempt`th attempt, counting from 1.
+    #[must_use]
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self.base.saturating_mul(1 << exponent).min(self.max);
+        // The clock is random enough to spread the reconnections.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |now| now.subsec_nanos());
+        let random = f64::from(nanos % 1000) / 1000.0;
+        delay + delay.mul_f64(self.jitter.clamp(0.0, 1.0) * random)
+    }
+}
+
+struct PendingEvent {
+    event_time: u64,
+    sequence: u64,
+    received: Instant,
+    event: WebsocketEvent,
+}
+
+impl PartialEq for PendingEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PendingEvent {}
+
+impl PartialOrd for PendingEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingEvent {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.event_time, self.sequence).cmp(&(other.event_time, other.sequence))
+    }
+}
+
+/// Reorders websocket events by their event time.
+///
+/// Events are held back for at most `window` so that an event that arrives
+/// late can still be delivered before the newer events. An event is released
+/// once an event at least `window` newer has been received, or once it has
+/// waited `window` in the buffer. A larger window tolerates more disorder at
+/// the cost of latency. Events without an event time are released
+/// immediately, and an event arriving later than the window is released as
+/// soon as possible, out of order.
+pub struct EventReorderBuffer {
+    window: Duration,
+    watermark: u64,
+    sequence: u64,
+    pending: BinaryHeap<Reverse<PendingEvent>>,
+    untimed: VecDeque<WebsocketEvent>,
+}
+
+impl EventReorderBuffer {
+    /// Create a buffer holding events back for at most `window`.
+    #[must_use]
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            watermark: 0,
+            sequence: 0,
+            pending: BinaryHeap::new(),
+            untimed: VecDeque::new(),
+        }
+    }
+
+    /// Add a received event to the buffer.
+    pub fn push(&mut self, event: WebsocketEvent) {
+        let Some(event_time) = event.event_time() else {
+            self.untimed.push_back(event);
+            return;
+        };
+        self.watermark = self.watermark.max(event_time);
+        self.sequence += 1;
+        self.pending.push(Reverse(PendingEvent {
+            event_time,
+            sequence: self.sequence,
+            received: Instant::now(),
+            event,
+        }));
+    }
+
+    /// Take the next event that is ready to be delivered, if any.
+    pub fn pop(&mut self) -> Option<WebsocketEvent> {
+        if let Some(event) = self.untimed.pop_front() {
+            return Some(event);
+        }
+        let Reverse(oldest) = self.pending.peek()?;
+        let window = u64::try_from(self.window.as_millis()).unwrap_or(u64::MAX);
+        if oldest.event_time.saturating_add(window) <= self.watermark
+            || oldest.received.elapsed() >= self.window
+        {
+            self.pending.pop().map(|Reverse(pending)| pending.event)
+        } else {
+            None
+        }
+    }
+
+    /// When the oldest buffered event will have waited the whole window,
+    /// `None` when no event is waiting.
+    fn release_deadline(&self) -> Option<Instant> {
+        let Reverse(oldest) = self.pending.peek()?;
+        Some(oldest.received + self.window)
+    }
+
+    /// Take every buffered event, in event time order, regardless of the
+    /// window.
+    pub fn flush(&mut self) -> Vec<WebsocketEvent> {
+        let mut events: Vec<WebsocketEvent> = self.untimed.drain(..).collect();
+        while let Some(Reverse(pending)) = self.pending.pop() {
+            events.push(pending.event);
+        }
+        events
+    }
+
+    /// Number of events in the buffer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len() + self.untimed.len()
+    }
+
+    /// Check if the buffer holds no event.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 pub struct WebSockets {
     pub read: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
     pub write: SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>,
+    reconnect: Option<(String, RetryConfig)>,
+    subscriptions: Vec<String>,
+    request_id: u64,
 }
 
+/// The `e` event types of the `Events` carrying one.
+const EVENT_TYPES: [&str; 8] = [
+    "balanceUpdate",
+    "24hrTicker",
+    "outboundAccountPosition",
+    "executionReport",
+    "aggTrade",
+    "trade",
+    "kline",
+    "depthUpdate",
+];
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 enum Events {
@@ -82,6 +368,9 @@ enum Events {
 }
 
 impl WebSockets {
+    /// How long `close_with` waits for the server to acknowledge the close.
+    pub const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Connect to the Binance websocket
     ///
     /// # Errors
@@ -93,6 +382,10 @@ impl WebSockets {
 
     /// Connect to the Binance websocket with a configuration
     ///
+    /// The subscription is appended to the `ws_endpoint` of the
+    /// configuration, as in `wss://stream.binance.com:9443/ws/btcusdt@trade`,
+    /// so the endpoint must not hold a stream name itself.
+    ///
     /// # Errors
     ///
     /// Returns an error if the connection cannot be established.
@@ -101,12 +394,57 @@ impl WebSockets {
             .await
     }
 
+    /// Connect to the Binance websocket, reconnecting when the connection
+    /// is lost
+    ///
+    /// See [`WebSockets::connect_with_reconnect_config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be established.
+    pub async fn connect_with_reconnect(subscription: &str, retry: RetryConfig) -> Result<Self> {
+        Self::connect_wss_with_reconnect(WebsocketAPI::Default.params(subscription), retry).await
+    }
+
+    /// Connect to the Binance websocket with a configuration, reconnecting
+    /// when the connection is lost
+    ///
+    /// When the stream closes or fails, `recv` connects again to the same
+    /// subscription, waiting between the attempts as set by `retry`, then
+    /// subscribes again to the streams added with `subscribe` and returns a
+    /// [`WebsocketEvent::Reconnected`] before the next events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the first connection cannot be established.
+    pub async fn connect_with_reconnect_config(
+        subscription: &str,
+        config: &Config,
+        retry: RetryConfig,
+    ) -> Result<Self> {
+        Self::connect_wss_with_reconnect(
+            WebsocketAPI::Custom(config.ws_endpoint.clone()).params(subscription),
+            retry,
+        )
+        .await
+    }
+
+    async fn connect_wss_with_reconnect(wss: String, retry: RetryConfig) -> Result<Self> {
+        let mut websocket = Self::connect_wss(&wss).await?;
+        websocket.reconnect = Some((wss, retry));
+        Ok(websocket)
+    }
+
     /// Connect to the Binance websocket with multiple streams
     ///
+    /// The streams are names such as `btcusdt@trade`, or
+    /// [`Stream`](crate::spot::stream::Stream)s.
+    ///
     /// # Errors
     ///
     /// Returns an error if the connection cannot be established.
-    pub async fn connect_multiple_streams(endpoints: &[String]) -> Result<Self> {
+    pub async fn connect_multiple_streams<S: AsRef<str>>(endpoints: &[S]) -> Result<Self> {
+        let endpoints: Vec<&str> = endpoints.iter().map(AsRef::as_ref).collect();
         Self::connect_wss(&WebsocketAPI::MultiStream.params(&endpoints.join("/"))).await
     }
 
@@ -124,7 +462,13 @@ impl WebSockets {
                 debug!("Response: {}", response.status());
                 debug!("Response: {:?}", response.body());
                 let (write, read) = socket.split();
-                Ok(Self { read, write })
+                Ok(Self {
+                    read,
+                    write,
+                    reconnect: None,
+                    subscriptions: vec![],
+                    request_id: 0,
+                })
             }
             Err(e) => bail!(format!("Error during handshake {}", e)),
         }
@@ -132,22 +476,145 @@ impl WebSockets {
 
     /// Disconnect from the websocket
     ///
+    /// A websocket connected with reconnection no longer reconnects.
+    ///
     /// # Errors
     ///
     /// Returns an error if the connection cannot be closed.
     pub async fn disconnect(&mut self) -> Result<()> {
+        self.reconnect = None;
         self.write.send(Message::Close(None)).await?;
         Ok(())
     }
 
-    fn handle_msg(msg: &str) -> Result<WebsocketEvent> {
-        let value: serde_json::Value = serde_json::from_str(msg)?;
+    /// Close the websocket with a status code and a reason
+    ///
+    /// Waits up to [`WebSockets::CLOSE_TIMEOUT`] for the server to
+    /// acknowledge the close, skipping the messages received meanwhile. A
+    /// websocket connected with reconnection no longer reconnects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the close frame cannot be sent.
+    pub async fn close_with(&mut self, code: CloseCode, reason: &str) -> Result<()> {
+        self.reconnect = None;
+        let frame = CloseFrame {
+            code,
+            reason: reason.to_owned().into(),
+        };
+        self.write.send(Message::Close(Some(frame))).await?;
+        let acknowledged = async {
+            while let Some(message) = self.read.next().await {
+                if matches!(message, Ok(Message::Close(_)) | Err(_)) {
+                    break;
+                }
+            }
+        };
+        if tokio::time::timeout(Self::CLOSE_TIMEOUT, acknowledged)
+            .await
+            .is_err()
+        {
+            debug!("Websocket close was not acknowledged");
+        }
+        Ok(())
+    }
+
+    /// Subscribe to more streams without reconnecting
+    ///
+    /// The streams are subscribed again when a websocket connected with
+    /// reconnection reconnects. Returns the id of the request, which the
+    /// matching [`WebsocketEvent::Response`] carries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent.
+    pub async fn subscribe(&mut self, streams: &[String]) -> Result<u64> {
+        let id = self.send_request("SUBSCRIBE", Some(streams)).await?;
+        for stream in streams {
+            if !self.subscriptions.contains(stream) {
+                self.subscriptions.push(stream.clone());
+            }
+        }
+        Ok(id)
+    }
+
+    /// Unsubscribe from streams without reconnecting
+    ///
+    /// Returns the id of the request, which the matching
+    /// [`WebsocketEvent::Response`] carries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent.
+    pub async fn unsubscribe(&mut self, streams: &[String]) -> Result<u64> {
+        let id = self.send_request("UNSUBSCRIBE", Some(streams)).await?;
+        self.subscriptions
+            .retain(|stream| !streams.contains(stream));
+        Ok(id)
+    }
 
+    /// Request the list of subscribed streams
+    ///
+    /// Returns the id of the request. The streams are listed in the `result`
+    /// of the matching [`WebsocketEvent::Response`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent.
+    pub async fn list_subscriptions(&mut self) -> Result<u64> {
+        self.send_request("LIST_SUBSCRIPTIONS", None).await
+    }
+
+    async fn send_request(&mut self, method: &str, streams: Option<&[String]>) -> Result<u64> {
+        self.request_id += 1;
+        let mut request = serde_json::json!({ "method": method, "id": self.request_id });
+        if let Some(streams) = streams {
+            request["params"] = serde_json::json!(streams);
+        }
+        self.write.send(Message::Text(request.to_string())).await?;
+        Ok(self.request_id)
+    }
+
+    /// Parse a message into its event and, for a combined stream, the name
+    /// of the stream it comes from.
+    fn handle_msg(msg: &str) -> Result<(Option<String>, WebsocketEvent)> {
+        let value: serde_json::Value = serde_json::from_str(msg)?;
         if let Some(data) = value.get("data") {
-            return Self::handle_msg(&data.to_string());
+            let stream = value
+                .get("stream")
+                .and_then(serde_json::Value::as_str)
+                .map(String::from);
+            return Ok((stream, Self::handle_event(data.clone())?));
+        }
+        Ok((None, Self::handle_event(value)?))
+    }
+
+    fn handle_event(value: serde_json::Value) -> Result<WebsocketEvent> {
+        if value.get("id").is_some() {
+            if let Some(error) = value.get("error") {
+                let error = serde_json::from_value::<BinanceContentError>(error.clone())?;
+                return Err(ErrorKind::BinanceError(error).into());
+            }
+            return Ok(WebsocketEvent::Response(serde_json::from_value(value)?));
+        }
+        // Only carrying `e` and `E`, it would match any untagged event.
+        if value.get("e").and_then(serde_json::Value::as_str) == Some("listenKeyExpired") {
+            return Ok(WebsocketEvent::ListenKeyExpired(serde_json::from_value(
+                value,
+            )?));
         }
 
-        let events = serde_json::from_value::<Events>(value)?;
+        if value
+            .get("e")
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|event_type| !EVENT_TYPES.contains(&event_type))
+        {
+            return Ok(WebsocketEvent::Unknown(value));
+        }
+
+        let Ok(events) = Events::deserialize(&value) else {
+            return Ok(WebsocketEvent::Unknown(value));
+        };
         let events = match events {
             Events::Vec(v) => WebsocketEvent::DayTickerAll(v),
             Events::BookTickerEvent(v) => WebsocketEvent::BookTicker(v),
@@ -168,9 +635,69 @@ impl WebSockets {
     ///
     /// # Errors
     ///
-    /// Returns an error if the message cannot be received.
+    /// Returns an error if the message cannot be received, or, for a
+    /// websocket connected with reconnection, if reconnecting failed
+    /// `max_attempts` times.
     pub async fn recv(&mut self) -> Result<Option<WebsocketEvent>> {
-        match self.read.next().await {
+        Ok(self.recv_within(None).await?.map(|(_, event)| event))
+    }
+
+    /// Receive a message from the websocket along with the name of the
+    /// stream it comes from
+    ///
+    /// The name is only known for the websockets connected with
+    /// [`WebSockets::connect_multiple_streams`], it is `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be received, see
+    /// [`WebSockets::recv`].
+    pub async fn recv_with_stream(&mut self) -> Result<Option<(Option<String>, WebsocketEvent)>> {
+        self.recv_within(None).await
+    }
+
+    /// Receive a message from the websocket, waiting at most `timeout`
+    ///
+    /// A connection that went silent, sending neither data nor a close
+    /// frame, is otherwise awaited forever. A websocket connected with
+    /// reconnection reconnects when the timeout elapses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ErrorKind::WebsocketTimeout` if no message is received
+    /// within `timeout`, or an error if the message cannot be received.
+    pub async fn recv_timeout(&mut self, timeout: Duration) -> Result<Option<WebsocketEvent>> {
+        Ok(self
+            .recv_within(Some(timeout))
+            .await?
+            .map(|(_, event)| event))
+    }
+
+    async fn recv_within(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> Result<Option<(Option<String>, WebsocketEvent)>> {
+        let message = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, self.read.next()).await {
+                Ok(message) => message,
+                Err(_) => {
+                    return self
+                        .reconnect(ErrorKind::WebsocketTimeout(timeout).into())
+                        .await
+                }
+            },
+            None => self.read.next().await,
+        };
+        self.handle_message(message).await
+    }
+
+    /// Parse a message read from the websocket, answering pings and
+    /// reconnecting when the stream closed or failed.
+    async fn handle_message(
+        &mut self,
+        message: Option<std::result::Result<Message, tokio_tungstenite::tungstenite::Error>>,
+    ) -> Result<Option<(Option<String>, WebsocketEvent)>> {
+        match message {
             Some(Ok(message)) => match message {
                 Message::Text(msg) => Ok(Some(Self::handle_msg(&msg)?)),
                 Message::Ping(payload) => {
@@ -179,13 +706,102 @@ impl WebSockets {
                     Ok(None)
                 }
                 Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => Ok(None),
-                Message::Close(e) => bail!(format!("Disconnected {:?}", e)),
+                Message::Close(e) => self.reconnect(format!("Disconnected {e:?}").into()).await,
             },
-            Some(Err(e)) => Err(e.into()),
+            Some(Err(e)) => self.reconnect(e.into()).await,
             None => {
                 debug!("Websocket connection closed");
-                Err("Websocket connection closed".into())
+                self.reconnect("Websocket connection closed".into()).await
+            }
+        }
+    }
+
+    /// Reconnect after the connection was lost with `error`, or return the
+    /// error if the websocket does not reconnect.
+    async fn reconnect(
+        &mut self,
+        error: Error,
+    ) -> Result<Option<(Option<String>, WebsocketEvent)>> {
+        let Some((wss, retry)) = self.reconnect.take() else {
+            return Err(error);
+        };
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            if retry.max_attempts.is_some_and(|max| attempts > max) {
+                return Err(error);
+            }
+            sleep(retry.delay(attempts)).await;
+            match Self::connect_wss(&wss).await {
+                Ok(websocket) => {
+                    self.read = websocket.read;
+                    self.write = websocket.write;
+                    self.reconnect = Some((wss, retry));
+                    if !self.subscriptions.is_empty() {
+                        let streams = self.subscriptions.clone();
+                        self.send_request("SUBSCRIBE", Some(&streams)).await?;
+                    }
+                    let event = WebsocketEvent::Reconnected(ReconnectEvent {
+                        attempts,
+                        reason: error.to_string(),
+                    });
+                    return Ok(Some((None, event)));
+                }
+                Err(e) => debug!("Reconnection attempt {} failed: {}", attempts, e),
             }
         }
     }
+
+    /// Turn the websocket into a stream of best bid and ask quotes
+    ///
+    /// Only `bookTicker` events are yielded, parsed into [`Quote`]s; other
+    /// events are skipped. The stream ends after the first error.
+    pub fn bookticker_quotes(self) -> impl Stream<Item = Result<Quote>> {
+        stream::unfold(Some(self), |websocket| async move {
+            let mut websocket = websocket?;
+            loop {
+                match websocket.recv().await {
+                    Ok(Some(WebsocketEvent::BookTicker(event))) => {
+                        return Some((Quote::try_from(&event), Some(websocket)));
+                    }
+                    Ok(_) => {}
+                    Err(e) => return Some((Err(e), None)),
+                }
+            }
+        })
+    }
+
+    /// Receive the next event in event time order
+    ///
+    /// Messages are read from the websocket into `buffer` and the events are
+    /// returned once the buffer releases them, see [`EventReorderBuffer`].
+    /// The websocket is read until the oldest buffered event has waited the
+    /// whole window, so it is released even if no more messages arrive.
+    /// Returns `None` when no event is ready yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be received.
+    pub async fn recv_ordered(
+        &mut self,
+        buffer: &mut EventReorderBuffer,
+    ) -> Result<Option<WebsocketEvent>> {
+        if let Some(event) = buffer.pop() {
+            return Ok(Some(event));
+        }
+        let message = match buffer.release_deadline() {
+            Some(deadline) => {
+                let deadline = tokio::time::Instant::from_std(deadline);
+                match tokio::time::timeout_at(deadline, self.read.next()).await {
+                    Ok(message) => message,
+                    Err(_) => return Ok(buffer.pop()),
+                }
+            }
+            None => self.read.next().await,
+        };
+        if let Some((_, event)) = self.handle_message(message).await? {
+            buffer.push(event);
+        }
+        Ok(buffer.pop())
+    }
 }
@@ -1,11 +1,70 @@
 use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use error_chain::bail;
+use serde::Serialize;
+use tracing::info;
+use tracing::warn;
 
+use crate::config::MAX_RECV_WINDOW;
+use crate::errors::Error;
+use crate::errors::ErrorKind;
 use crate::errors::Result;
 
+/// Tracing target of the order lifecycle events.
+///
+/// Every order placement and cancellation emits an event on this target, so
+/// order activity can be filtered independently of the request logging.
+pub const ORDER_TRACING_TARGET: &str = "binance::orders";
+
+/// Offset in milliseconds of the server clock over the local clock, added
+/// to the `timestamp` of the signed requests.
+///
+/// Clones share the same offset: the clients built from a `Config`, or from
+/// its clones, all use the offset last set or measured by
+/// `General::sync_time`.
+#[derive(Clone, Debug, Default)]
+pub struct TimeOffset(Arc<AtomicI64>);
+
+impl TimeOffset {
+    #[must_use]
+    pub fn new(offset: i64) -> Self {
+        Self(Arc::new(AtomicI64::new(offset)))
+    }
+
+    /// The offset in milliseconds, 0 until it is set or measured.
+    #[must_use]
+    pub fn get(&self) -> i64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Set the offset in milliseconds, for this handle and its clones.
+    pub fn set(&self, offset: i64) {
+        self.0.store(offset, Ordering::Relaxed);
+    }
+}
+
+/// Set `time_offset` from the `server_time` answered to a request sent at
+/// `sent`, taking the server time as of the middle of the round trip.
+pub(crate) fn sync_time_offset(
+    time_offset: &TimeOffset,
+    sent: SystemTime,
+    server_time: u64,
+) -> Result<i64> {
+    let round_trip = sent.elapsed().unwrap_or_default();
+    let local_time = get_timestamp(sent + round_trip / 2)?;
+    let offset =
+        i64::try_from(i128::from(server_time) - i128::from(local_time)).unwrap_or_default();
+    time_offset.set(offset);
+    Ok(offset)
+}
+
 #[must_use]
 pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     let mut request = String::new();
@@ -17,11 +76,30 @@ pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     request
 }
 
-/// Build a signed request
+/// Build the value of an array parameter, such as `symbols` or
+/// `orderIdList`, to insert in the request parameters.
+///
+/// The values are written as a compact JSON array, `["BTCUSDT","ETHUSDT"]`
+/// or `[1,2,3]`, then URL encoded so the brackets, quotes and commas survive
+/// the query string and the signature is computed over the sent value.
+///
+/// # Errors
+///
+/// Returns an error if the values cannot be serialized.
+pub fn build_array_param<T: Serialize>(values: &[T]) -> Result<String> {
+    let json = serde_json::to_string(values)?;
+    Ok(url::form_urlencoded::byte_serialize(json.as_bytes()).collect())
+}
+
+/// Build a signed request, timestamped with the local clock
+///
+/// Use `Client::build_signed_request` to correct the timestamp by the time
+/// offset of the client.
 ///
 /// # Errors
 ///
-/// Returns an error if the timestamp cannot be generated.
+/// Returns an error if `recv_window` exceeds the maximum accepted by Binance
+/// or if the timestamp cannot be generated.
 pub fn build_signed_request(
     parameters: BTreeMap<String, String>,
     recv_window: u64,
@@ -29,16 +107,31 @@ pub fn build_signed_request(
     build_signed_request_custom(parameters, recv_window, SystemTime::now())
 }
 
+/// The local time corrected by `offset` milliseconds.
+pub(crate) fn offset_now(offset: i64) -> SystemTime {
+    let now = SystemTime::now();
+    let offset_duration = Duration::from_millis(offset.unsigned_abs());
+    if offset >= 0 {
+        now + offset_duration
+    } else {
+        now - offset_duration
+    }
+}
+
 /// Build a signed request with a custom start time
 ///
 /// # Errors
 ///
-/// Returns an error if the timestamp cannot be generated.
+/// Returns an error if `recv_window` exceeds the maximum accepted by Binance
+/// or if the timestamp cannot be generated.
 pub fn build_signed_request_custom(
     mut parameters: BTreeMap<String, String>,
     recv_window: u64,
     start: SystemTime,
 ) -> Result<String> {
+    if recv_window > MAX_RECV_WINDOW {
+        bail!(ErrorKind::InvalidRecvWindow(recv_window));
+    }
     if recv_window > 0 {
         parameters.insert("recvWindow".into(), recv_window.to_string());
     }
@@ -53,3 +146,67 @@ fn get_timestamp(start: SystemTime) -> Result<u64> {
     let since_epoch = start.duration_since(UNIX_EPOCH)?;
     Ok(since_epoch.as_secs() * 1000 + u64::from(since_epoch.subsec_nanos()) / 1_000_000)
 }
+
+/// Emit an order placement event for an accepted order.
+pub(crate) fn trace_order_placed(
+    order: &BTreeMap<String, String>,
+    client_order_id: &str,
+    order_id: u64,
+    status: &str,
+) {
+    info!(
+        target: ORDER_TRACING_TARGET,
+        symbol = order_param(order, "symbol"),
+        side = order_param(order, "side"),
+        order_type = order_param(order, "type"),
+        qty = order_qty(order),
+        price = order_param(order, "price"),
+        client_order_id,
+        order_id,
+        status,
+        "order placed"
+    );
+}
+
+/// Emit an order placement event for an order the exchange did not accept.
+pub(crate) fn trace_order_rejected(order: &BTreeMap<String, String>, error: &Error) {
+    warn!(
+        target: ORDER_TRACING_TARGET,
+        symbol = order_param(order, "symbol"),
+        side = order_param(order, "side"),
+        order_type = order_param(order, "type"),
+        qty = order_qty(order),
+        price = order_param(order, "price"),
+        client_order_id = order_param(order, "newClientOrderId"),
+        error = %error,
+        "order rejected"
+    );
+}
+
+/// Emit an order cancellation event.
+pub(crate) fn trace_order_canceled(
+    symbol: &str,
+    client_order_id: Option<&str>,
+    order_id: Option<u64>,
+    status: Option<&str>,
+) {
+    info!(
+        target: ORDER_TRACING_TARGET,
+        symbol,
+        client_order_id = client_order_id.unwrap_or_default(),
+        order_id,
+        status = status.unwrap_or_default(),
+        "order canceled"
+    );
+}
+
+fn order_param<'a>(order: &'a BTreeMap<String, String>, key: &str) -> &'a str {
+    order.get(key).map_or("", String::as_str)
+}
+
+fn order_qty(order: &BTreeMap<String, String>) -> &str {
+    order
+        .get("quantity")
+        .or_else(|| order.get("quoteOrderQty"))
+        .map_or("", String::as_str)
+}
@@ -3,12 +3,61 @@ use binance::spot::account::*;
 
 #[cfg(test)]
 mod tests {
+    use std::convert::TryFrom;
+    use std::time::Duration;
+
+    use binance::errors::ErrorKind;
+    use binance::model::CommissionRates;
+    use binance::model::Filters;
+    use binance::spot::general::General;
+    use binance::spot::model::CancelReplaceResponse;
+    use binance::spot::model::OrderStatus;
+    use binance::spot::model::Symbol;
+    use binance::spot::model::Transaction;
     use float_cmp::*;
     use mockito::Matcher;
+    use rust_decimal::Decimal;
     use tokio::test;
 
     use super::*;
 
+    fn lot_size(min_qty: &str, max_qty: &str, step_size: &str) -> Filters {
+        Filters::LotSize {
+            min_qty: min_qty.into(),
+            max_qty: max_qty.into(),
+            step_size: step_size.into(),
+        }
+    }
+
+    fn min_notional(min_notional: &str, apply_to_market: bool) -> Filters {
+        Filters::MinNotional {
+            notional: None,
+            min_notional: Some(min_notional.into()),
+            apply_to_market: Some(apply_to_market),
+            avg_price_mins: Some(5.0),
+        }
+    }
+
+    fn symbol(filters: Vec<Filters>) -> Symbol {
+        Symbol {
+            symbol: "LTCBTC".into(),
+            status: "TRADING".into(),
+            base_asset: "LTC".into(),
+            base_asset_precision: 8,
+            quote_asset: "BTC".into(),
+            quote_precision: 8,
+            order_types: vec!["LIMIT".into(), "MARKET".into()],
+            iceberg_allowed: true,
+            is_spot_trading_allowed: true,
+            is_margin_trading_allowed: true,
+            filters,
+        }
+    }
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
     #[test]
     async fn get_account() {
         let mut server = mockito::Server::new_async().await;
@@ -37,6 +86,9 @@ mod tests {
         assert!(account.can_trade);
         assert!(account.can_withdraw);
         assert!(account.can_deposit);
+        assert!(account.commission_rates.is_none());
+        assert_eq!(account.account_type(), Some("SPOT"));
+        assert_eq!(account.permissions, vec!["SPOT"]);
 
         assert!(!account.balances.is_empty());
 
@@ -49,6 +101,354 @@ mod tests {
         assert_eq!(second_balance.asset, "LTC");
         assert_eq!(second_balance.free, "4763368.68006011");
         assert_eq!(second_balance.locked, "0.00000000");
+        assert_eq!(
+            second_balance.free_decimal().unwrap(),
+            Decimal::new(476_336_868_006_011, 8)
+        );
+        assert_eq!(second_balance.locked_decimal().unwrap(), Decimal::ZERO);
+    }
+
+    async fn commission_rates() -> CommissionRates {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_commission = server
+            .mock("GET", "/api/v3/account/commission")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_commission.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let commission_rates = account.get_commission("BTCUSDT").await.unwrap();
+        mock_get_commission.assert();
+        commission_rates
+    }
+
+    fn filled_transaction(order_type: &str, side: &str) -> Transaction {
+        serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": 1,
+            "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+            "transactTime": 1_507_725_176_595_u64,
+            "price": "50000",
+            "origQty": "0.02",
+            "executedQty": "0.02",
+            "cummulativeQuoteQty": "1000",
+            "status": "FILLED",
+            "timeInForce": "GTC",
+            "type": order_type,
+            "side": side,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    async fn effective_fee_maker_and_taker() {
+        let rates = commission_rates().await;
+
+        let maker = filled_transaction("LIMIT_MAKER", "BUY");
+        assert_eq!(
+            Account::effective_fee(&maker, &rates, false).unwrap(),
+            Decimal::new(8, 1)
+        );
+        let taker = filled_transaction("MARKET", "SELL");
+        assert_eq!(
+            Account::effective_fee(&taker, &rates, false).unwrap(),
+            Decimal::new(11, 1)
+        );
+    }
+
+    #[test]
+    async fn effective_fee_bnb_discount() {
+        let mut rates = commission_rates().await;
+
+        // Only the standard commission is discounted, not the tax.
+        let taker = filled_transaction("MARKET", "SELL");
+        assert_eq!(
+            Account::effective_fee(&taker, &rates, true).unwrap(),
+            Decimal::new(85, 2)
+        );
+        let maker = filled_transaction("LIMIT_MAKER", "BUY");
+        assert_eq!(
+            Account::effective_fee(&maker, &rates, true).unwrap(),
+            Decimal::new(6, 1)
+        );
+
+        rates.discount.enabled_for_symbol = false;
+        assert_eq!(
+            Account::effective_fee(&taker, &rates, true).unwrap(),
+            Decimal::new(11, 1)
+        );
+    }
+
+    #[test]
+    async fn effective_fee_from_fills() {
+        let rates = commission_rates().await;
+        let mut transaction: Transaction = serde_json::from_str(
+            &std::fs::read_to_string("tests/mocks/account/market_sell_using_quote_quantity.json")
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Account::effective_fee(&transaction, &rates, true).unwrap(),
+            Decimal::new(332_384, 8)
+        );
+
+        let mut fill = transaction.fills.as_ref().unwrap()[0].clone();
+        fill.commission_asset = "BTC".into();
+        transaction.fills.as_mut().unwrap().push(fill);
+        assert!(Account::effective_fee(&transaction, &rates, true).is_err());
+    }
+
+    #[test]
+    async fn order_status_serde() {
+        let status: OrderStatus = serde_json::from_str("\"PARTIALLY_FILLED\"").unwrap();
+        assert_eq!(status, OrderStatus::PartiallyFilled);
+        assert!(!status.is_terminal());
+        assert_eq!(
+            serde_json::to_string(&status).unwrap(),
+            "\"PARTIALLY_FILLED\""
+        );
+
+        let status: OrderStatus = serde_json::from_str("\"EXPIRED_IN_MATCH\"").unwrap();
+        assert!(status.is_terminal());
+
+        let status: OrderStatus = serde_json::from_str("\"NEW_STATUS\"").unwrap();
+        assert_eq!(status, OrderStatus::Other("NEW_STATUS".into()));
+        assert_eq!(status.to_string(), "NEW_STATUS");
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"NEW_STATUS\"");
+    }
+
+    #[test]
+    async fn order_type_and_side_serde() {
+        let order_type: OrderType = serde_json::from_str("\"STOP_LOSS_LIMIT\"").unwrap();
+        assert_eq!(order_type, OrderType::StopLossLimit);
+        let order_type: OrderType = serde_json::from_str("\"OTO\"").unwrap();
+        assert_eq!(order_type, OrderType::Other("OTO".into()));
+        assert_eq!(serde_json::to_string(&order_type).unwrap(), "\"OTO\"");
+
+        let side: OrderSide = serde_json::from_str("\"SELL\"").unwrap();
+        assert_eq!(side, OrderSide::Sell);
+        assert_eq!(serde_json::to_string(&OrderSide::Buy).unwrap(), "\"BUY\"");
+    }
+
+    #[test]
+    async fn with_recv_window() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=10000&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account
+            .with_recv_window(10_000)
+            .get_account()
+            .await
+            .unwrap();
+
+        mock_get_account.assert();
+        assert_eq!(account.recv_window, 1234);
+        assert!(account
+            .with_recv_window(60_001)
+            .get_account()
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn get_account_commission_rates() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account_commission_rates.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let account = account.get_account().await.unwrap();
+
+        mock_get_account.assert();
+
+        let commission_rates = account.commission_rates.as_ref().unwrap();
+        assert_eq!(commission_rates.maker, "0.00150000");
+        assert_eq!(commission_rates.taker, "0.00150000");
+        assert_eq!(commission_rates.buyer, "0.00000000");
+        assert_eq!(commission_rates.seller, "0.00000000");
+        assert_eq!(account.brokered, Some(false));
+        assert_eq!(account.require_self_trade_prevention, Some(false));
+        assert_eq!(account.account_type(), Some("SPOT"));
+        assert_eq!(account.update_time, Some(123_456_789));
+        assert_eq!(account.permissions, vec!["SPOT", "MARGIN"]);
+    }
+
+    #[test]
+    async fn get_account_filtered() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^omitZeroBalances=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account_commission_rates.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let account = account.get_account_filtered(true).await.unwrap();
+
+        mock_get_account.assert();
+
+        assert_eq!(account.balances.len(), 1);
+        assert_eq!(account.balances[0].asset, "BTC");
+    }
+
+    #[test]
+    async fn max_buy_qty() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let mut general = General::new_with_config(None, None, &config).unwrap();
+        general.update_cache().await.unwrap();
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let qty = account
+            .max_buy_qty("LTCBTC", dec("100"), false, &mut general)
+            .await
+            .unwrap();
+
+        mock_exchange_info.assert();
+        mock_get_account.assert();
+
+        // 4723846.89208129 BTC / 100, rounded down to the 0.01 step.
+        assert_eq!(qty, dec("47238.46"));
+    }
+
+    #[test]
+    async fn max_buy_qty_filters() {
+        let filters = symbol(vec![
+            lot_size("0.01", "1000", "0.01"),
+            min_notional("0.001", false),
+        ]);
+
+        // Rounded down to the step.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("1.23456"), Decimal::ZERO, dec("0.1"), false)
+                .unwrap(),
+            dec("12.34")
+        );
+        // Capped by the maximum quantity.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("500"), Decimal::ZERO, dec("0.1"), false)
+                .unwrap(),
+            dec("1000")
+        );
+        // Below the minimum quantity.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("0.0009"), Decimal::ZERO, dec("0.1"), false)
+                .unwrap(),
+            Decimal::ZERO
+        );
+        // Below the minimum notional for a limit order, but the filter does
+        // not apply to market orders.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("0.0009"), Decimal::ZERO, dec("0.01"), false)
+                .unwrap(),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("0.0009"), Decimal::ZERO, dec("0.01"), true)
+                .unwrap(),
+            dec("0.09")
+        );
+        assert!(filters
+            .max_buy_qty(dec("1"), Decimal::ZERO, Decimal::ZERO, false)
+            .is_err());
+    }
+
+    #[test]
+    async fn max_buy_qty_position_and_market_lot_size() {
+        let filters = symbol(vec![
+            lot_size("0.01", "1000", "0.01"),
+            Filters::MarketLotSize {
+                min_qty: "1".into(),
+                max_qty: "50".into(),
+                step_size: "1".into(),
+            },
+            Filters::MaxPosition {
+                max_position: "100".into(),
+            },
+        ]);
+
+        // Limited by the room left under the maximum position.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("100"), dec("60.5"), dec("1"), false)
+                .unwrap(),
+            dec("39.5")
+        );
+        // Already at the maximum position.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("100"), dec("100"), dec("1"), false)
+                .unwrap(),
+            Decimal::ZERO
+        );
+        // Market orders use the market lot size.
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("100"), Decimal::ZERO, dec("1"), true)
+                .unwrap(),
+            dec("50")
+        );
+        assert_eq!(
+            filters
+                .max_buy_qty(dec("100"), dec("60.5"), dec("1"), true)
+                .unwrap(),
+            dec("39")
+        );
     }
 
     #[test]
@@ -77,6 +477,34 @@ mod tests {
         assert_eq!(balance.locked, "0.00000000");
     }
 
+    #[test]
+    async fn get_all_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_all_orders = server
+            .mock("GET", "/api/v3/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^limit=1000&orderId=1&recvWindow=1234&startTime=1499827319000&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_open_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let orders = account
+            .get_all_orders("LTCBTC", 1, 1_499_827_319_000, None, 1000)
+            .await
+            .unwrap();
+
+        mock_get_all_orders.assert();
+
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].symbol, "LTCBTC");
+        assert_eq!(orders[0].order_id, 1);
+    }
+
     #[test]
     async fn get_open_orders() {
         let mut server = mockito::Server::new_async().await;
@@ -109,10 +537,10 @@ mod tests {
         assert_eq!(open_order.orig_qty, "1.0");
         assert_eq!(open_order.executed_qty, "0.0");
         assert_eq!(open_order.cummulative_quote_qty, "0.0");
-        assert_eq!(open_order.status, "NEW");
+        assert_eq!(open_order.status, OrderStatus::New);
         assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(open_order.type_name, "LIMIT");
-        assert_eq!(open_order.side, "BUY");
+        assert_eq!(open_order.type_name, OrderType::Limit);
+        assert_eq!(open_order.side, OrderSide::Buy);
         assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
         assert_eq!(open_order.iceberg_qty, "0.0");
         assert_eq!(open_order.time, 1_499_827_319_559);
@@ -151,10 +579,10 @@ mod tests {
         assert_eq!(open_order.orig_qty, "1.0");
         assert_eq!(open_order.executed_qty, "0.0");
         assert_eq!(open_order.cummulative_quote_qty, "0.0");
-        assert_eq!(open_order.status, "NEW");
+        assert_eq!(open_order.status, OrderStatus::New);
         assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(open_order.type_name, "LIMIT");
-        assert_eq!(open_order.side, "BUY");
+        assert_eq!(open_order.type_name, OrderType::Limit);
+        assert_eq!(open_order.side, OrderSide::Buy);
         assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
         assert_eq!(open_order.iceberg_qty, "0.0");
         assert_eq!(open_order.time, 1_499_827_319_559);
@@ -211,6 +639,53 @@ mod tests {
         );
     }
 
+    #[test]
+    async fn cancel_all_orders_global() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_open_orders = server
+            .mock("GET", "/api/v3/openOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("recvWindow=1234&timestamp=\\d+".into()))
+            .with_body_from_file("tests/mocks/account/get_open_orders_multiple_symbols.json")
+            .create();
+        let mock_cancel_ethbtc = server
+            .mock("DELETE", "/api/v3/openOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=ETHBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_all_open_orders.json")
+            .expect(1)
+            .create();
+        let mock_cancel_ltcbtc = server
+            .mock("DELETE", "/api/v3/openOrders")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body(r#"{"code":-2011,"msg":"Unknown order sent."}"#)
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let results = account.cancel_all_orders_global(4).await.unwrap();
+
+        mock_open_orders.assert();
+        mock_cancel_ethbtc.assert();
+        mock_cancel_ltcbtc.assert();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "ETHBTC");
+        assert_eq!(results[0].1.as_ref().unwrap().len(), 3);
+        assert_eq!(results[1].0, "LTCBTC");
+        assert!(results[1].1.is_err());
+    }
+
     #[test]
     async fn order_status() {
         let mut server = mockito::Server::new_async().await;
@@ -235,15 +710,16 @@ mod tests {
         assert_eq!(order_status.symbol, "LTCBTC");
         assert_eq!(order_status.order_id, 1);
         assert_eq!(order_status.order_list_id, -1);
+        assert!(order_status.order_list().is_none());
         assert_eq!(order_status.client_order_id, "myOrder1");
         assert!(approx_eq!(f64, order_status.price, 0.1, ulps = 2));
         assert_eq!(order_status.orig_qty, "1.0");
         assert_eq!(order_status.executed_qty, "0.0");
         assert_eq!(order_status.cummulative_quote_qty, "0.0");
-        assert_eq!(order_status.status, "NEW");
+        assert_eq!(order_status.status, OrderStatus::New);
         assert_eq!(order_status.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(order_status.type_name, "LIMIT");
-        assert_eq!(order_status.side, "BUY");
+        assert_eq!(order_status.type_name, OrderType::Limit);
+        assert_eq!(order_status.side, OrderSide::Buy);
         assert!(approx_eq!(f64, order_status.stop_price, 0.0, ulps = 2));
         assert_eq!(order_status.iceberg_qty, "0.0");
         assert_eq!(order_status.time, 1_499_827_319_559);
@@ -253,15 +729,31 @@ mod tests {
     }
 
     #[test]
-    async fn test_order_status() {
+    async fn place_and_wait() {
         let mut server = mockito::Server::new_async().await;
-        let mock_test_order_status = server
-            .mock("GET", "/api/v3/order/test")
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("side=BUY&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+        let mock_order_new = server
+            .mock("GET", "/api/v3/order")
             .with_header("content-type", "application/json;charset=UTF-8")
             .match_query(Matcher::Regex(
                 "orderId=1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
             ))
-            .with_body("{}")
+            .with_body_from_file("tests/mocks/account/order_status.json")
+            .expect(1)
+            .create();
+        let mock_order_filled = server
+            .mock("GET", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_status_filled.json")
+            .expect(1)
             .create();
 
         let config = Config::default()
@@ -269,27 +761,143 @@ mod tests {
             .set_recv_window(1234);
         let account = Account::new_with_config(None, None, &config).unwrap();
         let _ = env_logger::try_init();
-        account.test_order_status("LTCBTC", 1).await.unwrap();
+        let order = account
+            .place_and_wait(
+                account.limit_buy("LTCBTC", 1, 0.1),
+                Duration::from_millis(1),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
 
-        mock_test_order_status.assert();
+        mock_limit_buy.assert();
+        mock_order_new.assert();
+        mock_order_filled.assert();
+
+        assert_eq!(order.order_id, 1);
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.executed_qty, "1.0");
+        assert!(order.is_terminal());
     }
 
     #[test]
-    async fn limit_buy() {
+    async fn place_and_wait_already_terminal() {
         let mut server = mockito::Server::new_async().await;
-        let mock_limit_buy =server.mock("POST", "/api/v3/order")
+        let mock_market_buy = server
+            .mock("POST", "/api/v3/order")
             .with_header("content-type", "application/json;charset=UTF-8")
-            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into()))
-            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .match_query(Matcher::Regex("side=BUY&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/account/market_buy_filled.json")
+            .create();
+        let mock_order_status = server
+            .mock("GET", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .expect(0)
             .create();
 
         let config = Config::default()
             .set_rest_api_endpoint(server.url())
             .set_recv_window(1234);
         let account = Account::new_with_config(None, None, &config).unwrap();
-        let _ = env_logger::try_init();
-        let transaction = account.limit_buy("LTCBTC", 1, 0.1).await.unwrap();
-
+        let order = account
+            .place_and_wait(
+                account.market_buy("LTCBTC", 1),
+                Duration::from_millis(1),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+
+        mock_market_buy.assert();
+        mock_order_status.assert();
+
+        assert_eq!(order.order_id, 1);
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.executed_qty, "1");
+    }
+
+    #[test]
+    async fn place_and_wait_timeout() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("side=BUY&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+        let mock_order_new = server
+            .mock("GET", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/account/order_status.json")
+            .expect_at_least(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let error = account
+            .place_and_wait(
+                account.limit_buy("LTCBTC", 1, 0.1),
+                Duration::from_millis(10),
+                Duration::from_millis(100),
+            )
+            .await
+            .unwrap_err();
+
+        mock_limit_buy.assert();
+        mock_order_new.assert();
+
+        match error.kind() {
+            ErrorKind::OrderTimeout(Some(order), timeout) => {
+                assert_eq!(order.order_id, 1);
+                assert_eq!(order.symbol, "LTCBTC");
+                assert_eq!(order.status, OrderStatus::New);
+                assert_eq!(*timeout, Duration::from_millis(100));
+            }
+            kind => panic!("unexpected error {:?}", kind),
+        }
+    }
+
+    #[test]
+    async fn test_order_status() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_test_order_status = server
+            .mock("GET", "/api/v3/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        account.test_order_status("LTCBTC", 1).await.unwrap();
+
+        mock_test_order_status.assert();
+    }
+
+    #[test]
+    async fn limit_buy() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_limit_buy =server.mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let transaction = account.limit_buy("LTCBTC", 1, 0.1).await.unwrap();
+
         mock_limit_buy.assert();
 
         assert_eq!(transaction.symbol, "LTCBTC");
@@ -306,10 +914,133 @@ mod tests {
             0.0,
             ulps = 2
         ));
-        assert_eq!(transaction.status, "NEW");
+        assert_eq!(transaction.status, OrderStatus::New);
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "LIMIT");
-        assert_eq!(transaction.side, "BUY");
+        assert_eq!(transaction.type_name, OrderType::Limit);
+        assert_eq!(transaction.side, OrderSide::Buy);
+    }
+
+    #[test]
+    async fn limit_buy_params_in_body() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Missing)
+            .match_header("content-type", "application/x-www-form-urlencoded")
+            .match_body(Matcher::Regex("^price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into()))
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_post_params_in_body(true);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let transaction = account.limit_buy("LTCBTC", 1, 0.1).await.unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.order_id, 1);
+    }
+
+    #[test]
+    async fn get_account_with_time_offset() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=100000000\\d{4}&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        // Shifts the timestamps to 1_000_000_000_000, give or take the
+        // duration of the test.
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_time_offset(1_000_000_000_000 - i64::try_from(now).unwrap());
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account.get_account().await.unwrap();
+
+        mock_get_account.assert();
+    }
+
+    #[test]
+    async fn limit_buy_with_timeout() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_limit_buy =server.mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("newClientOrderId=6gCrw2kRUAF9CvJDGP16IP&price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let transaction = account
+            .limit_buy_with_timeout(
+                "LTCBTC",
+                1,
+                0.1,
+                "6gCrw2kRUAF9CvJDGP16IP",
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.symbol, "LTCBTC");
+        assert_eq!(transaction.order_id, 1);
+        assert_eq!(transaction.status, OrderStatus::New);
+    }
+
+    #[test]
+    async fn limit_buy_timed_out() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("side=BUY&symbol=LTCBTC".into()))
+            .with_chunked_body(|w| {
+                std::thread::sleep(Duration::from_millis(500));
+                w.write_all(include_bytes!("mocks/account/limit_buy.json"))
+            })
+            .create();
+        let mock_order_status = server
+            .mock("GET", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=my-order&recvWindow=5000&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_status.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let result = account
+            .limit_buy_with_timeout("LTCBTC", 1, 0.1, "my-order", Duration::from_millis(50))
+            .await;
+
+        mock_limit_buy.assert();
+
+        assert!(result.is_err());
+
+        // The order the timed out request placed can be looked up.
+        let order = account
+            .order_status_with_client_id("LTCBTC", "my-order".into())
+            .await
+            .unwrap();
+        mock_order_status.assert();
+        assert_eq!(order.order_id, 1);
     }
 
     #[test]
@@ -331,6 +1062,39 @@ mod tests {
         mock_test_limit_buy.assert();
     }
 
+    #[test]
+    async fn order_list() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_order_status = server
+            .mock("GET", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=2&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_status_order_list.json")
+            .create();
+        let mock_limit_sell = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("side=SELL&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/account/limit_sell_order_list.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let order_status = account.order_status("LTCBTC", 2).await.unwrap();
+        let transaction = account.limit_sell("LTCBTC", 1, 0.1).await.unwrap();
+
+        mock_order_status.assert();
+        mock_limit_sell.assert();
+
+        assert_eq!(order_status.order_list(), Some(12));
+        assert_eq!(transaction.order_list(), Some(12));
+    }
+
     #[test]
     async fn limit_sell() {
         let mut server = mockito::Server::new_async().await;
@@ -352,6 +1116,7 @@ mod tests {
         assert_eq!(transaction.symbol, "LTCBTC");
         assert_eq!(transaction.order_id, 1);
         assert_eq!(transaction.order_list_id.unwrap(), -1);
+        assert!(transaction.order_list().is_none());
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1_507_725_176_595);
         assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
@@ -363,10 +1128,10 @@ mod tests {
             0.0,
             ulps = 2
         ));
-        assert_eq!(transaction.status, "NEW");
+        assert_eq!(transaction.status, OrderStatus::New);
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "LIMIT");
-        assert_eq!(transaction.side, "SELL");
+        assert_eq!(transaction.type_name, OrderType::Limit);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
@@ -395,7 +1160,7 @@ mod tests {
             .mock("POST", "/api/v3/order")
             .with_header("content-type", "application/json;charset=UTF-8")
             .match_query(Matcher::Regex(
-                "quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timestamp=\\d+&type=MARKET"
+                "^newOrderRespType=FULL&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timestamp=\\d+&type=MARKET&signature=.*$"
                     .into(),
             ))
             .with_body_from_file("tests/mocks/account/market_buy.json")
@@ -424,10 +1189,10 @@ mod tests {
             0.0,
             ulps = 2
         ));
-        assert_eq!(transaction.status, "NEW");
+        assert_eq!(transaction.status, OrderStatus::New);
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "MARKET");
-        assert_eq!(transaction.side, "BUY");
+        assert_eq!(transaction.type_name, OrderType::Market);
+        assert_eq!(transaction.side, OrderSide::Buy);
     }
 
     #[test]
@@ -538,10 +1303,10 @@ mod tests {
             0.0,
             ulps = 2
         ));
-        assert_eq!(transaction.status, "NEW");
+        assert_eq!(transaction.status, OrderStatus::New);
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "MARKET");
-        assert_eq!(transaction.side, "SELL");
+        assert_eq!(transaction.type_name, OrderType::Market);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
@@ -652,10 +1417,10 @@ mod tests {
             ulps = 2
         ));
         assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
-        assert_eq!(transaction.status, "NEW");
+        assert_eq!(transaction.status, OrderStatus::New);
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
-        assert_eq!(transaction.side, "BUY");
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+        assert_eq!(transaction.side, OrderSide::Buy);
     }
 
     #[test]
@@ -716,19 +1481,19 @@ mod tests {
             ulps = 2
         ));
         assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
-        assert_eq!(transaction.status, "NEW");
+        assert_eq!(transaction.status, OrderStatus::New);
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
-        assert_eq!(transaction.side, "SELL");
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+        assert_eq!(transaction.side, OrderSide::Sell);
     }
 
     #[test]
-    async fn test_stop_limit_sell_order() {
+    async fn trailing_stop_sell() {
         let mut server = mockito::Server::new_async().await;
-        let mock_test_stop_limit_sell_order =server.mock("POST", "/api/v3/order/test")
+        let mock_trailing_stop_sell = server.mock("POST", "/api/v3/order")
             .with_header("content-type", "application/json;charset=UTF-8")
-            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=SELL&stopPrice=0.09&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=STOP_LOSS_LIMIT".into()))
-            .with_body("{}")
+            .match_query(Matcher::Regex("^price=0.1&quantity=1&recvWindow=1234&side=SELL&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&trailingDelta=200&type=STOP_LOSS_LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/stop_limit_sell.json")
             .create();
 
         let config = Config::default()
@@ -736,20 +1501,22 @@ mod tests {
             .set_recv_window(1234);
         let account = Account::new_with_config(None, None, &config).unwrap();
         let _ = env_logger::try_init();
-        account
-            .test_stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+        let transaction = account
+            .trailing_stop_sell("LTCBTC", 1, 0.1, None, 200, TimeInForce::GTC)
             .await
             .unwrap();
 
-        mock_test_stop_limit_sell_order.assert();
+        mock_trailing_stop_sell.assert();
+
+        assert_eq!(transaction.symbol, "LTCBTC");
     }
 
     #[test]
-    async fn custom_order() {
+    async fn trailing_stop_sell_with_activation_price() {
         let mut server = mockito::Server::new_async().await;
-        let mock_custom_order =server.mock("POST", "/api/v3/order")
+        let mock_trailing_stop_sell = server.mock("POST", "/api/v3/order")
             .with_header("content-type", "application/json;charset=UTF-8")
-            .match_query(Matcher::Regex("newClientOrderId=6gCrw2kRUAF9CvJDGP16IP&price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=MARKET".into()))
+            .match_query(Matcher::Regex("^price=0.1&quantity=1&recvWindow=1234&side=SELL&stopPrice=0.12&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&trailingDelta=200&type=TAKE_PROFIT_LIMIT".into()))
             .with_body_from_file("tests/mocks/account/stop_limit_sell.json")
             .create();
 
@@ -758,49 +1525,20 @@ mod tests {
             .set_recv_window(1234);
         let account = Account::new_with_config(None, None, &config).unwrap();
         let _ = env_logger::try_init();
-        let transaction = account
-            .custom_order(
-                "LTCBTC",
-                1,
-                0.1,
-                None,
-                OrderSide::Buy,
-                OrderType::Market,
-                TimeInForce::GTC,
-                Some("6gCrw2kRUAF9CvJDGP16IP".into()),
-            )
+        account
+            .trailing_stop_sell("LTCBTC", 1, 0.1, Some(0.12), 200, TimeInForce::GTC)
             .await
             .unwrap();
 
-        mock_custom_order.assert();
-
-        assert_eq!(transaction.symbol, "LTCBTC");
-        assert_eq!(transaction.order_id, 1);
-        assert_eq!(transaction.order_list_id.unwrap(), -1);
-        assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
-        assert_eq!(transaction.transact_time, 1_507_725_176_595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
-        assert_eq!(transaction.status, "NEW");
-        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
-        assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
-        assert_eq!(transaction.side, "SELL");
+        mock_trailing_stop_sell.assert();
     }
 
     #[test]
-    async fn test_custom_order() {
+    async fn test_stop_limit_sell_order() {
         let mut server = mockito::Server::new_async().await;
-        let mock_test_custom_order =server.mock("POST", "/api/v3/order/test")
+        let mock_test_stop_limit_sell_order =server.mock("POST", "/api/v3/order/test")
             .with_header("content-type", "application/json;charset=UTF-8")
-            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=MARKET".into()))
+            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=SELL&stopPrice=0.09&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=STOP_LOSS_LIMIT".into()))
             .with_body("{}")
             .create();
 
@@ -810,53 +1548,658 @@ mod tests {
         let account = Account::new_with_config(None, None, &config).unwrap();
         let _ = env_logger::try_init();
         account
-            .test_custom_order(
-                "LTCBTC",
-                1,
-                0.1,
-                None,
-                OrderSide::Buy,
-                OrderType::Market,
-                TimeInForce::GTC,
-                None,
-            )
+            .test_stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
             .await
             .unwrap();
 
-        mock_test_custom_order.assert();
+        mock_test_stop_limit_sell_order.assert();
     }
 
     #[test]
-    async fn cancel_order() {
+    async fn limit_maker_sell() {
         let mut server = mockito::Server::new_async().await;
-        let mock_cancel_order = server
-            .mock("DELETE", "/api/v3/order")
+        let mock_limit_maker_sell = server
+            .mock("POST", "/api/v3/order")
             .with_header("content-type", "application/json;charset=UTF-8")
             .match_query(Matcher::Regex(
-                "orderId=1&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+".into(),
+                "^price=0.1&quantity=1&recvWindow=1234&side=SELL&symbol=LTCBTC&timestamp=\\d+&type=LIMIT_MAKER&signature=.*$".into(),
             ))
-            .with_body_from_file("tests/mocks/account/cancel_order.json")
+            .with_body_from_file("tests/mocks/account/limit_sell.json")
             .create();
 
         let config = Config::default()
             .set_rest_api_endpoint(server.url())
             .set_recv_window(1234);
         let account = Account::new_with_config(None, None, &config).unwrap();
-        let _ = env_logger::try_init();
-        let cancelled_order = account.cancel_order("BTCUSDT", 1).await.unwrap();
+        let transaction = account.limit_maker_sell("LTCBTC", 1, 0.1).await.unwrap();
 
-        mock_cancel_order.assert();
+        mock_limit_maker_sell.assert();
 
-        assert_eq!(cancelled_order.symbol, "LTCBTC");
-        assert_eq!(cancelled_order.orig_client_order_id.unwrap(), "myOrder1");
-        assert_eq!(cancelled_order.order_id.unwrap(), 4);
-        assert_eq!(cancelled_order.client_order_id.unwrap(), "cancelMyOrder1");
+        assert_eq!(transaction.symbol, "LTCBTC");
     }
 
     #[test]
-    async fn test_cancel_order() {
+    async fn limit_maker_custom_order() {
         let mut server = mockito::Server::new_async().await;
-        let mock_test_cancel_order = server
+        let mock_custom_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timestamp=\\d+&type=LIMIT_MAKER&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account
+            .custom_order(
+                "LTCBTC",
+                1,
+                0.1,
+                None,
+                OrderSide::Buy,
+                OrderType::LimitMaker,
+                TimeInForce::GTC,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock_custom_order.assert();
+    }
+
+    #[test]
+    async fn iceberg_place_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_custom_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^icebergQty=0.2&price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let order = OrderRequest::new("LTCBTC", 1, 0.1, OrderSide::Buy, OrderType::Limit)
+            .set_iceberg_qty(0.2);
+        account.place_order(order).await.unwrap();
+
+        mock_custom_order.assert();
+    }
+
+    #[test]
+    async fn iceberg_limit_sell() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_iceberg_limit_sell = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^icebergQty=0.2&price=0.1&quantity=1&recvWindow=1234&side=SELL&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_sell.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let transaction = account
+            .iceberg_limit_sell("LTCBTC", 1, 0.1, 0.2)
+            .await
+            .unwrap();
+
+        mock_iceberg_limit_sell.assert();
+
+        assert_eq!(transaction.symbol, "LTCBTC");
+    }
+
+    #[test]
+    async fn custom_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_custom_order =server.mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("newClientOrderId=6gCrw2kRUAF9CvJDGP16IP&newOrderRespType=FULL&price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=MARKET".into()))
+            .with_body_from_file("tests/mocks/account/stop_limit_sell.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let transaction = account
+            .custom_order(
+                "LTCBTC",
+                1,
+                0.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Market,
+                TimeInForce::GTC,
+                Some("6gCrw2kRUAF9CvJDGP16IP".into()),
+            )
+            .await
+            .unwrap();
+
+        mock_custom_order.assert();
+
+        assert_eq!(transaction.symbol, "LTCBTC");
+        assert_eq!(transaction.order_id, 1);
+        assert_eq!(transaction.order_list_id.unwrap(), -1);
+        assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
+        assert_eq!(transaction.transact_time, 1_507_725_176_595);
+        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
+        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
+        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            transaction.cummulative_quote_qty,
+            0.0,
+            ulps = 2
+        ));
+        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_eq!(transaction.status, OrderStatus::New);
+        assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
+        assert_eq!(transaction.type_name, OrderType::StopLossLimit);
+        assert_eq!(transaction.side, OrderSide::Sell);
+    }
+
+    #[test]
+    async fn place_order_response_type() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_custom_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^newOrderRespType=ACK&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timestamp=\\d+&type=MARKET&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/market_buy_ack.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let ack = account
+            .place_order_ack(OrderRequest::new(
+                "LTCBTC",
+                1,
+                0.0,
+                OrderSide::Buy,
+                OrderType::Market,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(ack.symbol, "LTCBTC");
+        assert_eq!(ack.order_id, 28);
+        assert_eq!(ack.order_list(), None);
+        assert_eq!(ack.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
+        assert_eq!(ack.transact_time, 1_507_725_176_595);
+
+        // An ACK cannot be parsed into a transaction, so it is not sent.
+        let order = OrderRequest::new("LTCBTC", 1, 0.0, OrderSide::Buy, OrderType::Market)
+            .set_new_order_resp_type(OrderResponseType::Ack);
+        assert!(account.place_order(order).await.is_err());
+
+        mock_custom_order.assert();
+    }
+
+    #[test]
+    async fn place_order_self_trade_prevention() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_custom_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^price=0.1&quantity=1&recvWindow=1234&selfTradePreventionMode=EXPIRE_BOTH&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let order = OrderRequest::new("LTCBTC", 1, 0.1, OrderSide::Buy, OrderType::Limit)
+            .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireBoth);
+        account.place_order(order).await.unwrap();
+
+        mock_custom_order.assert();
+    }
+
+    #[test]
+    async fn cancel_replace_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^cancelOrderId=4&cancelReplaceMode=STOP_ON_FAILURE&price=2.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let replaced = account
+            .cancel_replace_order(
+                "LTCBTC",
+                4,
+                CancelReplaceMode::StopOnFailure,
+                1,
+                2.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::GTC,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock_cancel_replace.assert();
+
+        assert_eq!(replaced.cancel_result, "SUCCESS");
+        assert_eq!(replaced.new_order_result, "SUCCESS");
+        assert_eq!(replaced.cancel_response.order_id, Some(4));
+        assert_eq!(replaced.new_order_response.order_id, 5);
+        assert_eq!(replaced.new_order_response.status, OrderStatus::New);
+        assert!(approx_eq!(
+            f64,
+            replaced.new_order_response.price,
+            2.1,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    async fn cancel_replace_order_partially_failed() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_status(409)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^cancelOrderId=4&cancelReplaceMode=ALLOW_FAILURE&price=2.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace_partially_failed.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let error = account
+            .cancel_replace_order(
+                "LTCBTC",
+                4,
+                CancelReplaceMode::AllowFailure,
+                1,
+                2.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::GTC,
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        mock_cancel_replace.assert();
+
+        let ErrorKind::CancelReplacePartiallyFailed(failure) = error.kind() else {
+            panic!("unexpected error: {}", error);
+        };
+        assert_eq!(failure.cancel_result, "FAILURE");
+        assert_eq!(failure.new_order_result, "SUCCESS");
+        let CancelReplaceResponse::Failure(cancel_error) = &failure.cancel_response else {
+            panic!("cancel should have failed");
+        };
+        assert_eq!(cancel_error.code, -2011);
+        let CancelReplaceResponse::Success(transaction) = &failure.new_order_response else {
+            panic!("new order should have been placed");
+        };
+        assert_eq!(transaction.order_id, 5);
+        assert_eq!(transaction.status, OrderStatus::New);
+    }
+
+    #[test]
+    async fn cancel_replace_order_with_client_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^cancelOrigClientOrderId=myOrder1&cancelReplaceMode=ALLOW_FAILURE&price=2.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account
+            .cancel_replace_order_with_client_id(
+                "LTCBTC",
+                "myOrder1".into(),
+                CancelReplaceMode::AllowFailure,
+                1,
+                2.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::GTC,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock_cancel_replace.assert();
+    }
+
+    #[test]
+    async fn test_custom_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_test_custom_order =server.mock("POST", "/api/v3/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=MARKET".into()))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        account
+            .test_custom_order(
+                "LTCBTC",
+                1,
+                0.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Market,
+                TimeInForce::GTC,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock_test_custom_order.assert();
+    }
+
+    #[test]
+    async fn place_oco_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_oco_order = server.mock("POST", "/api/v3/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^price=0.036435&quantity=0.624363&recvWindow=1234&side=BUY&stopLimitPrice=0.97&stopLimitTimeInForce=GTC&stopPrice=0.960664&symbol=LTCBTC&timestamp=\\d+&signature=.*".into()))
+            .with_body_from_file("tests/mocks/account/oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let oco_order = account
+            .place_oco_order(
+                "LTCBTC",
+                OrderSide::Buy,
+                0.624_363,
+                0.036_435,
+                0.960_664,
+                0.97,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock_oco_order.assert();
+
+        assert_eq!(oco_order.order_list_id, 0);
+        assert_eq!(oco_order.contingency_type, "OCO");
+        assert_eq!(oco_order.list_status_type, "EXEC_STARTED");
+        assert_eq!(oco_order.list_order_status, "EXECUTING");
+        assert_eq!(oco_order.list_client_order_id, "JYVpp3F0f5CAG15DhtrqLp");
+        assert_eq!(oco_order.transaction_time, 1_563_417_480_525);
+        assert_eq!(oco_order.orders.len(), 2);
+        assert_eq!(oco_order.orders[1].order_id, 3);
+        assert_eq!(oco_order.order_reports.len(), 2);
+        assert_eq!(oco_order.order_reports[0].type_name, OrderType::StopLoss);
+        assert!(approx_eq!(
+            f64,
+            oco_order.order_reports[0].stop_price,
+            0.960_664,
+            ulps = 2
+        ));
+        assert_eq!(oco_order.order_reports[1].type_name, OrderType::LimitMaker);
+        assert_eq!(oco_order.order_reports[1].order_list(), Some(0));
+    }
+
+    #[test]
+    async fn place_oco_order_with_options() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_oco_order = server.mock("POST", "/api/v3/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^listClientOrderId=my-oco&price=0.036435&quantity=0.624363&recvWindow=1234&side=SELL&stopLimitPrice=0.97&stopLimitTimeInForce=FOK&stopPrice=0.960664&symbol=LTCBTC&timestamp=\\d+".into()))
+            .with_body_from_file("tests/mocks/account/oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account
+            .place_oco_order(
+                "LTCBTC",
+                OrderSide::Sell,
+                0.624_363,
+                0.036_435,
+                0.960_664,
+                0.97,
+                Some(TimeInForce::FOK),
+                Some("my-oco".into()),
+            )
+            .await
+            .unwrap();
+
+        mock_oco_order.assert();
+    }
+
+    #[test]
+    async fn cancel_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_cancel_order = server
+            .mock("DELETE", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let cancelled_order = account.cancel_order("BTCUSDT", 1).await.unwrap();
+
+        mock_cancel_order.assert();
+
+        assert_eq!(cancelled_order.symbol, "LTCBTC");
+        assert_eq!(cancelled_order.orig_client_order_id.unwrap(), "myOrder1");
+        assert_eq!(cancelled_order.order_id.unwrap(), 4);
+        assert_eq!(cancelled_order.client_order_id.unwrap(), "cancelMyOrder1");
+    }
+
+    #[test]
+    async fn get_oco_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_oco_order = server
+            .mock("GET", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^orderListId=27&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let oco_order = account.get_oco_order(27).await.unwrap();
+
+        mock_get_oco_order.assert();
+
+        assert_eq!(oco_order.order_list_id, 27);
+        assert_eq!(oco_order.list_order_status, "EXECUTING");
+        assert_eq!(oco_order.orders.len(), 2);
+        assert_eq!(
+            oco_order.orders[0].client_order_id,
+            "qD1gy3kc3Gx0rihm9Y3xwS"
+        );
+        assert!(oco_order.order_reports.is_empty());
+    }
+
+    #[test]
+    async fn get_all_oco_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_all_oco_orders = server
+            .mock("GET", "/api/v3/allOrderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^fromId=28&limit=10&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_all_oco_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let oco_orders = account
+            .get_all_oco_orders(28, None, None, 10)
+            .await
+            .unwrap();
+
+        mock_get_all_oco_orders.assert();
+
+        assert_eq!(oco_orders.len(), 2);
+        assert_eq!(oco_orders[0].order_list_id, 29);
+        assert_eq!(oco_orders[1].list_status_type, "ALL_DONE");
+    }
+
+    #[test]
+    async fn get_open_oco_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_open_oco_orders = server
+            .mock("GET", "/api/v3/openOrderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^recvWindow=1234&timestamp=\\d+".into()))
+            .with_body_from_file("tests/mocks/account/get_all_oco_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let oco_orders = account.get_open_oco_orders().await.unwrap();
+
+        mock_get_open_oco_orders.assert();
+
+        assert_eq!(oco_orders.len(), 2);
+        assert_eq!(oco_orders[0].symbol, "LTCBTC");
+    }
+
+    #[test]
+    async fn cancel_oco_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_cancel_oco_order = server
+            .mock("DELETE", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^orderListId=0&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let canceled = account.cancel_oco_order("LTCBTC", 0, None).await.unwrap();
+
+        mock_cancel_oco_order.assert();
+
+        assert_eq!(canceled.order_list_id, 0);
+        assert_eq!(canceled.list_status_type, "ALL_DONE");
+        assert_eq!(canceled.list_order_status, "ALL_DONE");
+        assert_eq!(canceled.list_client_order_id, "C3wyj4WVEktd7u9aVBRXcN");
+        assert_eq!(canceled.orders.len(), 2);
+        assert_eq!(canceled.order_reports.len(), 2);
+        let stop_loss = &canceled.order_reports[0];
+        assert_eq!(stop_loss.orig_client_order_id, "pO9ufTiFGg3nw2fOdgeOXa");
+        assert_eq!(stop_loss.order_id, 2);
+        assert_eq!(stop_loss.status, OrderStatus::Canceled);
+        assert_eq!(stop_loss.type_name, OrderType::StopLossLimit);
+        assert!(approx_eq!(f64, stop_loss.stop_price, 1.0, ulps = 2));
+        let limit_maker = &canceled.order_reports[1];
+        assert_eq!(limit_maker.type_name, OrderType::LimitMaker);
+        assert!(approx_eq!(f64, limit_maker.stop_price, 0.0, ulps = 2));
+    }
+
+    #[test]
+    async fn cancel_oco_order_with_client_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_cancel_oco_order = server
+            .mock("DELETE", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^listClientOrderId=C3wyj4WVEktd7u9aVBRXcN&newClientOrderId=unfWT8ig8i0uj6lPuYLez6&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let canceled = account
+            .cancel_oco_order_with_client_id(
+                "LTCBTC",
+                "C3wyj4WVEktd7u9aVBRXcN".into(),
+                Some("unfWT8ig8i0uj6lPuYLez6".into()),
+            )
+            .await
+            .unwrap();
+
+        mock_cancel_oco_order.assert();
+
+        assert_eq!(
+            canceled.order_reports[0].client_order_id,
+            "unfWT8ig8i0uj6lPuYLez6"
+        );
+    }
+
+    #[test]
+    async fn test_cancel_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_test_cancel_order = server
             .mock("DELETE", "/api/v3/order/test")
             .with_header("content-type", "application/json;charset=UTF-8")
             .match_query(Matcher::Regex(
@@ -910,4 +2253,100 @@ mod tests {
         assert!(!history.is_maker);
         assert!(history.is_best_match);
     }
+
+    #[test]
+    async fn trade_history_with_options() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_trade_history = server
+            .mock("GET", "/api/v3/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^endTime=1499865600000&fromId=28457&limit=1000&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/trade_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let histories = account
+            .trade_history_with_options("BTCUSDT", None, 1_499_865_600_000, 28457, None, 1000)
+            .await
+            .unwrap();
+
+        mock_trade_history.assert();
+
+        assert_eq!(histories.len(), 1);
+        assert_eq!(histories[0].id, 28457);
+    }
+
+    #[test]
+    async fn my_allocations() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_my_allocations = server
+            .mock("GET", "/api/v3/myAllocations")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^fromAllocationId=10&limit=100&recvWindow=1234&startTime=1499827319000&symbol=BTCUSDT&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/my_allocations.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let allocations = account
+            .my_allocations("BTCUSDT", 1_499_827_319_000, None, 10, None, 100)
+            .await
+            .unwrap();
+
+        mock_my_allocations.assert();
+
+        assert_eq!(allocations.len(), 1);
+
+        let allocation = &allocations[0];
+
+        assert_eq!(allocation.symbol, "BTCUSDT");
+        assert_eq!(allocation.allocation_id, 0);
+        assert_eq!(allocation.allocation_type, "SOR");
+        assert_eq!(allocation.order_id, 1);
+        assert!(approx_eq!(f64, allocation.price, 1.0, ulps = 2));
+        assert!(approx_eq!(f64, allocation.qty, 5.0, ulps = 2));
+        assert!(approx_eq!(f64, allocation.quote_qty, 5.0, ulps = 2));
+        assert_eq!(allocation.commission, "0.00000000");
+        assert_eq!(allocation.commission_asset, "BTC");
+        assert_eq!(allocation.time, 1_687_506_878_118);
+        assert!(allocation.is_buyer);
+        assert!(!allocation.is_maker);
+    }
+
+    #[test]
+    async fn trades_for_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_trades_for_order = server
+            .mock("GET", "/api/v3/myTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=100234&recvWindow=1234&symbol=BNBBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/trade_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let histories = account.trades_for_order("BNBBTC", 100_234).await.unwrap();
+
+        mock_trades_for_order.assert();
+
+        assert!(histories.len() == 1);
+        assert_eq!(histories[0].id, 28457);
+    }
 }
@@ -0,0 +1,51 @@
+use binance::api::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn futures_routes() {
+        assert_eq!(String::from(API::Futures(Futures::Depth)), "/fapi/v1/depth");
+        assert_eq!(
+            String::from(API::Futures(Futures::PositionRisk)),
+            "/fapi/v2/positionRisk"
+        );
+        assert_eq!(
+            String::from(API::Futures(Futures::Account)),
+            "/fapi/v2/account"
+        );
+        assert_eq!(
+            String::from(API::Futures(Futures::PositionMarginHistory)),
+            "/fapi/v1/positionMargin/history"
+        );
+    }
+
+    #[test]
+    fn delivery_routes() {
+        assert_eq!(
+            String::from(API::Delivery(Futures::Depth)),
+            "/dapi/v1/depth"
+        );
+        assert_eq!(
+            String::from(API::Delivery(Futures::PremiumIndex)),
+            "/dapi/v1/premiumIndex"
+        );
+        assert_eq!(
+            String::from(API::Delivery(Futures::PositionRisk)),
+            "/dapi/v1/positionRisk"
+        );
+        assert_eq!(
+            String::from(API::Delivery(Futures::Account)),
+            "/dapi/v1/account"
+        );
+        assert_eq!(
+            String::from(API::Delivery(Futures::UserDataStream)),
+            "/dapi/v1/listenKey"
+        );
+        assert_eq!(
+            String::from(API::Delivery(Futures::TakerlongshortRatio)),
+            "/futures/data/takerBuySellVol"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use binance::config::*;
+
+#[cfg(test)]
+mod tests {
+    use binance::errors::ErrorKind;
+
+    use super::*;
+
+    #[test]
+    fn testnet() {
+        let config = Config::testnet();
+        assert_eq!(config.rest_api_endpoint, "https://testnet.binance.vision");
+        assert_eq!(config.ws_endpoint, "wss://testnet.binance.vision/ws");
+        assert_eq!(
+            config.futures_rest_api_endpoint,
+            "https://testnet.binancefuture.com"
+        );
+        assert_eq!(
+            config.futures_ws_endpoint,
+            "wss://fstream.binancefuture.com/ws"
+        );
+
+        let config = Config::spot_testnet();
+        assert_eq!(config.rest_api_endpoint, "https://testnet.binance.vision");
+        assert_eq!(config.futures_rest_api_endpoint, "https://fapi.binance.com");
+
+        let config = Config::futures_testnet();
+        assert_eq!(config.rest_api_endpoint, "https://api.binance.com");
+        assert_eq!(
+            config.delivery_rest_api_endpoint,
+            "https://testnet.binancefuture.com"
+        );
+    }
+
+    #[test]
+    fn try_set_recv_window() {
+        let config = Config::default()
+            .try_set_recv_window(MAX_RECV_WINDOW)
+            .unwrap();
+        assert_eq!(config.recv_window, 60_000);
+
+        let result = Config::default().try_set_recv_window(MAX_RECV_WINDOW + 1);
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            ErrorKind::InvalidRecvWindow(60_001)
+        ));
+    }
+}
@@ -42,6 +42,111 @@ mod tests {
         ));
     }
 
+    #[test]
+    async fn change_initial_leverage_out_of_range() {
+        let config = Config::default().set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+
+        assert!(account.change_initial_leverage("LTCUSDT", 0).await.is_err());
+        assert!(account
+            .change_initial_leverage("LTCUSDT", 126)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn position_information() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v2/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/position_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let positions = account.position_information("BTCUSDT").await.unwrap();
+
+        mock.assert();
+
+        assert_eq!(positions.len(), 1);
+        let position = &positions[0];
+        assert_eq!(position.symbol, "BTCUSDT");
+        assert_eq!(position.position_side, "LONG");
+        assert_eq!(position.margin_type, "isolated");
+        assert_eq!(position.leverage, "10");
+        assert!(approx_eq!(f64, position.entry_price, 30_000.0, ulps = 2));
+        assert!(approx_eq!(f64, position.mark_price, 30_150.5, ulps = 2));
+        assert!(approx_eq!(f64, position.unrealized_profit, 1.505, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            position.liquidation_price,
+            27_300.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    async fn position_information_all_symbols() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v2/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/position_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let positions = account.position_information(None).await.unwrap();
+
+        mock.assert();
+
+        assert_eq!(positions.len(), 1);
+    }
+
+    #[test]
+    async fn account_balance() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v2/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/account_balance.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let balances = account.account_balance().await.unwrap();
+
+        mock.assert();
+
+        assert_eq!(balances.len(), 1);
+        let balance = &balances[0];
+        assert_eq!(balance.asset, "USDT");
+        assert!(approx_eq!(f64, balance.balance, 122.607_353_51, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            balance.available_balance,
+            119.608_273_37,
+            ulps = 2
+        ));
+        assert!(balance.margin_available);
+    }
+
     #[test]
     async fn cancel_all_open_orders() {
         let mut server = mockito::Server::new_async().await;
@@ -86,6 +191,97 @@ mod tests {
         mock.assert();
     }
 
+    #[test]
+    async fn change_margin_type() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/fapi/v1/marginType")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^marginType=ISOLATED&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*$"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/change_margin_type.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account
+            .change_margin_type("BTCUSDT", MarginType::Isolated)
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    async fn change_margin_type_unchanged() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/fapi/v1/marginType")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^marginType=CROSSED&".into()))
+            .with_body(r#"{"code":-4046,"msg":"No need to change margin type."}"#)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account
+            .change_margin_type("BTCUSDT", MarginType::Crossed)
+            .await
+            .unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    async fn change_position_mode_unchanged() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/fapi/v1/positionSide/dual")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^dualSidePosition=false&".into()))
+            .with_body(r#"{"code":-4059,"msg":"No need to change position side."}"#)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        account.change_position_mode(false).await.unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    async fn get_position_mode() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v1/positionSide/dual")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_position_mode.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let position_mode = account.get_position_mode().await.unwrap();
+
+        mock.assert();
+
+        assert!(position_mode.dual_side_position);
+    }
+
     #[test]
     async fn stop_market_close_buy() {
         let mut server = mockito::Server::new_async().await;
@@ -171,6 +367,7 @@ mod tests {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            price_match: None,
         };
         let transaction: Transaction = account.custom_order(custom_order).await.unwrap();
 
@@ -183,6 +380,244 @@ mod tests {
         assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
     }
 
+    #[test]
+    async fn place_order() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_place_order = server
+            .mock("POST", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^activationPrice=30500&callbackRate=1&positionSide=LONG&quantity=0.01\
+                &recvWindow=1234&reduceOnly=TRUE&side=SELL&symbol=BTCUSDT&timestamp=\\d+\
+                &type=TRAILING_STOP_MARKET&workingType=MARK_PRICE&signature=.*$"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/trailing_stop_market_sell.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let order = CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            position_side: Some(PositionSide::Long),
+            order_type: OrderType::TrailingStopMarket,
+            time_in_force: None,
+            qty: Some(0.01),
+            reduce_only: Some(true),
+            price: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: Some(30_500.0),
+            callback_rate: Some(1.0),
+            working_type: Some(WorkingType::MarkPrice),
+            price_protect: None,
+            price_match: None,
+        };
+        let transaction = account.place_order(order).await.unwrap();
+
+        mock_place_order.assert();
+
+        assert_eq!(transaction.orig_type, "TRAILING_STOP_MARKET");
+        assert_eq!(transaction.position_side, "LONG");
+        assert_eq!(transaction.working_type, "MARK_PRICE");
+        assert!(transaction.reduce_only);
+        assert!(approx_eq!(
+            f64,
+            transaction.activate_price.unwrap(),
+            30_500.0,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            transaction.price_rate.unwrap(),
+            1.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    async fn limit_buy_post_only() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_limit_buy = server
+            .mock("POST", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^price=29990.1&quantity=0.01&recvWindow=1234&side=BUY&symbol=BTCUSDT\
+                &timeInForce=GTX&timestamp=\\d+&type=LIMIT&signature=.*$"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/limit_buy_gtx.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let transaction = account
+            .limit_buy("BTCUSDT", 0.01, 29_990.1, TimeInForce::GTX)
+            .await
+            .unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.order_id, 3);
+        assert_eq!(transaction.time_in_force, "GTX");
+    }
+
+    #[test]
+    async fn custom_order_with_price_match() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_custom_order = server
+            .mock("POST", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^priceMatch=QUEUE&quantity=0.01&recvWindow=1234&side=BUY&symbol=BTCUSDT\
+                &timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*$"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/limit_buy_price_match.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let custom_order = CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            qty: Some(0.01),
+            reduce_only: None,
+            price: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            price_match: Some(PriceMatch::QUEUE),
+        };
+        let transaction: Transaction = account.custom_order(custom_order).await.unwrap();
+
+        mock_custom_order.assert();
+
+        assert_eq!(transaction.symbol, "BTCUSDT");
+        assert_eq!(transaction.side, "BUY");
+        assert_eq!(transaction.orig_type, "LIMIT");
+    }
+
+    #[test]
+    async fn custom_order_with_price_and_price_match() {
+        let config = Config::default().set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let custom_order = CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            qty: Some(0.01),
+            reduce_only: None,
+            price: Some(30_000.0),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            price_match: Some(PriceMatch::QUEUE),
+        };
+
+        assert!(account.custom_order(custom_order).await.is_err());
+    }
+
+    #[test]
+    async fn modify_isolated_margin() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_modify_margin = server
+            .mock("POST", "/fapi/v1/positionMargin")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=100&positionSide=LONG&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+\
+                &type=1&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_isolated_margin.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let response = account
+            .modify_isolated_margin(
+                "BTCUSDT",
+                100.0,
+                Some(PositionSide::Long),
+                PositionMarginType::Add,
+            )
+            .await
+            .unwrap();
+
+        mock_modify_margin.assert();
+
+        assert!(approx_eq!(f64, response.amount, 100.0, ulps = 2));
+        assert_eq!(response.code, 200);
+        assert_eq!(response.type_name, 1);
+
+        assert!(account
+            .modify_isolated_margin("BTCUSDT", 0.0, None, PositionMarginType::Reduce)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn position_margin_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_margin_history = server
+            .mock("GET", "/fapi/v1/positionMargin/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=10&recvWindow=1234&startTime=1578047800000&symbol=BTCUSDT\
+                &timestamp=\\d+&type=1&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/position_margin_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let history = account
+            .position_margin_history(
+                "BTCUSDT",
+                Some(PositionMarginType::Add),
+                Some(1_578_047_800_000),
+                None,
+                10,
+            )
+            .await
+            .unwrap();
+
+        mock_margin_history.assert();
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].symbol, "BTCUSDT");
+        assert_eq!(history[0].type_name, 1);
+        assert_eq!(history[0].delta_type.as_deref(), Some("USER_ADJUST"));
+        assert!(approx_eq!(f64, history[0].amount, 23.363_323_11, ulps = 2));
+        assert_eq!(history[1].position_side, "LONG");
+    }
+
     #[test]
     async fn get_income() {
         let mut server = mockito::Server::new_async().await;
@@ -213,4 +648,43 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    async fn income_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^incomeType=COMMISSION&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*$"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_income_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account = Account::new_with_config(None, None, &config).unwrap();
+        let income = account
+            .income_history(
+                Some("BTCUSDT".into()),
+                Some(IncomeType::COMMISSION),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(income.len(), 2);
+        assert_eq!(income[1].symbol, "BTCUSDT");
+        assert_eq!(income[1].income_type, "COMMISSION");
+        assert!(approx_eq!(f64, income[1].income, -0.01, ulps = 2));
+        assert_eq!(income[1].asset, "USDT");
+        assert_eq!(income[1].time, 1_570_636_800_000);
+        assert_eq!(income[1].tran_id, 9_689_322_392);
+    }
 }
@@ -0,0 +1,62 @@
+use binance::config::*;
+use binance::futures::general::*;
+use binance::futures::FuturesProduct;
+use rust_decimal::Decimal;
+
+#[cfg(test)]
+mod tests {
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    async fn coin_m_contract_conversion() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_exchange_info = server
+            .mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/general/coin_m_exchange_info.json")
+            .create();
+
+        let config = Config::default().set_delivery_rest_api_endpoint(server.url());
+        let mut general =
+            General::new_with_product(None, None, &config, FuturesProduct::COINM).unwrap();
+        assert!(general
+            .contracts_to_coins("BTCUSD_PERP", Decimal::ONE, Decimal::ONE)
+            .is_err());
+
+        general.update_cache().await.unwrap();
+        mock_exchange_info.assert();
+
+        let symbol = general.get_symbol_info("BTCUSD_PERP").unwrap();
+        assert_eq!(symbol.status, "TRADING");
+        assert_eq!(symbol.contract_size, Some(100));
+
+        let price = Decimal::new(50_000, 0);
+        assert_eq!(
+            general
+                .contracts_to_coins("BTCUSD_PERP", Decimal::new(5, 0), price)
+                .unwrap(),
+            Decimal::new(1, 2)
+        );
+        assert_eq!(
+            general
+                .coins_to_contracts("btcusd_perp", Decimal::new(1, 2), price)
+                .unwrap(),
+            Decimal::new(5, 0)
+        );
+        assert_eq!(
+            general
+                .coins_to_contracts("ETHUSD_PERP", Decimal::ONE, Decimal::new(2_500, 0))
+                .unwrap(),
+            Decimal::new(250, 0)
+        );
+
+        assert!(general
+            .contracts_to_coins("BTCUSD_PERP", Decimal::ONE, Decimal::ZERO)
+            .is_err());
+        assert!(general
+            .coins_to_contracts("XRPUSD_PERP", Decimal::ONE, price)
+            .is_err());
+    }
+}
@@ -1,14 +1,120 @@
 use binance::config::*;
+use binance::futures::account::ContractType;
+use binance::futures::market::FuturesDepthLimit;
+use binance::futures::market::FuturesProduct;
 use binance::futures::market::Market;
 use binance::futures::model::OpenInterestHist;
+use binance::futures::model::Trades;
+use binance::model::KlineSummaries;
 
 #[cfg(test)]
 mod tests {
+    use float_cmp::*;
     use mockito::Matcher;
+    use rust_decimal::Decimal;
     use tokio::test;
 
     use super::*;
 
+    #[test]
+    async fn market_summary() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_all_24h_price_stats = server
+            .mock("GET", "/fapi/v1/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/market/all_24h_price_stats.json")
+            .create();
+        let mut mock_open_interests = Vec::new();
+        for (symbol, open_interest) in [("BTCUSDT", "2"), ("ETHUSDC", "10"), ("USDCUSDT", "50")] {
+            let mock_open_interest = server
+                .mock("GET", "/fapi/v1/openInterest")
+                .with_header("content-type", "application/json;charset=UTF-8")
+                .match_query(Matcher::Regex(format!("^symbol={symbol}$")))
+                .with_body(format!(
+                    "{{\"openInterest\": \"{open_interest}\", \"symbol\": \"{symbol}\", \"time\": 1589437530011}}"
+                ))
+                .create();
+            mock_open_interests.push(mock_open_interest);
+        }
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let summary = market.market_summary(None).await.unwrap();
+        mock_all_24h_price_stats.assert();
+        for mock_open_interest in mock_open_interests {
+            mock_open_interest.assert();
+        }
+
+        // ETHUSDC is converted at the 0.5 USDT per USDC of USDCUSDT.
+        assert_eq!(summary.symbol_count, 3);
+        assert!(summary.failed_symbols.is_empty());
+        assert!(approx_eq!(
+            f64,
+            summary.total_oi_usdt,
+            100_000.0 + 10_000.0 + 25.0,
+            epsilon = 1e-6
+        ));
+        assert!(approx_eq!(
+            f64,
+            summary.total_volume_usdt,
+            1_000_000.0 + 250_000.0 + 200.0,
+            epsilon = 1e-6
+        ));
+
+        assert!(market
+            .for_product(FuturesProduct::COINM)
+            .market_summary(2)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn market_summary_skips_failed_symbols() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock_all_24h_price_stats = server
+            .mock("GET", "/fapi/v1/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/market/all_24h_price_stats.json")
+            .create();
+        let _mock_btc_open_interest = server
+            .mock("GET", "/fapi/v1/openInterest")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=BTCUSDT$".into()))
+            .with_body(
+                "{\"openInterest\": \"2\", \"symbol\": \"BTCUSDT\", \"time\": 1589437530011}",
+            )
+            .create();
+        let _mock_failed_open_interest = server
+            .mock("GET", "/fapi/v1/openInterest")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=(ETHUSDC|USDCUSDT)$".into()))
+            .with_body("{\"code\": -1121, \"msg\": \"Invalid symbol.\"}")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let summary = market.market_summary(1).await.unwrap();
+
+        assert_eq!(summary.symbol_count, 1);
+        assert_eq!(summary.failed_symbols, vec!["ETHUSDC", "USDCUSDT"]);
+        assert!(approx_eq!(
+            f64,
+            summary.total_oi_usdt,
+            100_000.0,
+            epsilon = 1e-6
+        ));
+        assert!(approx_eq!(
+            f64,
+            summary.total_volume_usdt,
+            1_000_000.0,
+            epsilon = 1e-6
+        ));
+    }
+
     #[test]
     async fn open_interest_statistics() {
         let mut server = mockito::Server::new_async().await;
@@ -45,4 +151,372 @@ mod tests {
 
         assert_eq!(open_interest_hists, expectation);
     }
+
+    #[test]
+    async fn coin_m_depth() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_depth = server
+            .mock("GET", "/dapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSD_PERP".into()))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_depth.json")
+            .create();
+
+        let config = Config::default().set_delivery_rest_api_endpoint(server.url());
+        let market = Market::new_with_product(None, None, &config, FuturesProduct::COINM).unwrap();
+
+        let order_book = market.get_depth("BTCUSD_PERP").await.unwrap();
+        mock_depth.assert();
+
+        assert_eq!(order_book.last_update_id, 16_769_853);
+        assert_eq!(order_book.bids.len(), 1);
+        assert_eq!(order_book.asks.len(), 1);
+    }
+
+    #[test]
+    async fn for_product() {
+        let mut usdm_server = mockito::Server::new_async().await;
+        let mut coinm_server = mockito::Server::new_async().await;
+        let mock_usdm_depth = usdm_server
+            .mock("GET", "/fapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_depth.json")
+            .create();
+        let mock_depth = coinm_server
+            .mock("GET", "/dapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSD_PERP".into()))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_depth.json")
+            .create();
+        let mock_trades = coinm_server
+            .mock("GET", "/dapi/v1/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "7")
+            .match_query(Matcher::Regex("symbol=BTCUSD_PERP".into()))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_trades.json")
+            .create();
+        let mock_price_stats = coinm_server
+            .mock("GET", "/dapi/v1/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSD_200925".into()))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_24h_price_stats.json")
+            .create();
+        let mock_klines = coinm_server
+            .mock("GET", "/dapi/v1/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "interval=1m&limit=1&symbol=BTCUSD_PERP".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_klines.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(usdm_server.url())
+            .set_delivery_rest_api_endpoint(coinm_server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+        let coin_m = market.for_product(FuturesProduct::COINM);
+        assert_eq!(coin_m.product, FuturesProduct::COINM);
+
+        market.get_depth("BTCUSDT").await.unwrap();
+        let order_book = coin_m.get_depth("BTCUSD_PERP").await.unwrap();
+        let trades = coin_m.get_trades("BTCUSD_PERP").await.unwrap();
+        let price_stats = coin_m.get_24h_price_stats("BTCUSD_200925").await.unwrap();
+        let klines = coin_m
+            .get_klines("BTCUSD_PERP", "1m", 1, None, None)
+            .await
+            .unwrap();
+
+        mock_usdm_depth.assert();
+        mock_depth.assert();
+        mock_trades.assert();
+        mock_price_stats.assert();
+        mock_klines.assert();
+
+        assert_eq!(order_book.last_update_id, 16_769_853);
+        let Trades::AllTrades(trades) = trades;
+        assert_eq!(trades[0].id, 28457);
+        assert_eq!(trades[0].base_qty, Some(0.010_378_83));
+        assert_eq!(price_stats.symbol, "BTCUSD_200925");
+        assert_eq!(price_stats.base_volume, Some(5_192.947_976_87));
+        let KlineSummaries::AllKlineSummaries(klines) = klines;
+        assert_eq!(klines[0].close, "9642.0");
+        // The rate limits are per IP, whichever product is queried.
+        assert_eq!(market.client.used_weight(), Some(7));
+    }
+
+    #[test]
+    async fn funding_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_funding_info = server
+            .mock("GET", "/fapi/v1/fundingInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/market/funding_info.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let funding_info = market.funding_info().await.unwrap();
+        mock_funding_info.assert();
+
+        assert_eq!(funding_info.len(), 2);
+        assert_eq!(funding_info[0].symbol, "BLZUSDT");
+        assert_eq!(
+            funding_info[0].adjusted_funding_rate_cap,
+            Decimal::new(25, 3)
+        );
+        assert_eq!(
+            funding_info[0].adjusted_funding_rate_floor,
+            Decimal::new(-25, 3)
+        );
+        assert_eq!(funding_info[0].funding_interval_hours, 8);
+        assert_eq!(funding_info[1].symbol, "ETHFIUSDT");
+        assert_eq!(funding_info[1].funding_interval_hours, 4);
+    }
+
+    #[test]
+    async fn get_continuous_klines() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_klines = server
+            .mock("GET", "/fapi/v1/continuousKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^contractType=CURRENT_QUARTER&interval=1m&limit=1&pair=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/continuous_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let klines = market
+            .get_continuous_klines("BTCUSDT", ContractType::CurrentQuarter, "1m", 1, None, None)
+            .await
+            .unwrap();
+        mock_klines.assert();
+
+        let KlineSummaries::AllKlineSummaries(klines) = klines;
+        assert_eq!(klines.len(), 1);
+        assert_eq!(klines[0].open_time, 1_607_444_700_000);
+        assert_eq!(klines[0].close, "18896.13");
+        assert_eq!(klines[0].number_of_trades, 1874);
+    }
+
+    #[test]
+    async fn get_index_and_mark_price_klines() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_index_klines = server
+            .mock("GET", "/fapi/v1/indexPriceKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^endTime=1591256459999&interval=1m&pair=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/mark_price_klines.json")
+            .create();
+        let mock_mark_klines = server
+            .mock("GET", "/fapi/v1/markPriceKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^interval=1m&startTime=1591256400000&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/mark_price_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let index_klines = market
+            .get_index_price_klines("BTCUSDT", "1m", None, None, 1_591_256_459_999)
+            .await
+            .unwrap();
+        let mark_klines = market
+            .get_mark_price_klines("BTCUSDT", "1m", None, 1_591_256_400_000, None)
+            .await
+            .unwrap();
+        mock_index_klines.assert();
+        mock_mark_klines.assert();
+
+        let KlineSummaries::AllKlineSummaries(index_klines) = index_klines;
+        assert_eq!(index_klines[0].close, "9651.55200000");
+        let KlineSummaries::AllKlineSummaries(mark_klines) = mark_klines;
+        assert_eq!(mark_klines[0].volume, "0");
+    }
+
+    #[test]
+    async fn top_long_short_position_ratio() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_ratio = server
+            .mock("GET", "/futures/data/topLongShortPositionRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=2&period=5m&symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/top_long_short_position_ratio.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let ratios = market
+            .top_long_short_position_ratio("BTCUSDT", "5m", 2, None, None)
+            .await
+            .unwrap();
+        mock_ratio.assert();
+
+        assert_eq!(ratios.len(), 2);
+        assert_eq!(ratios[0].symbol, "BTCUSDT");
+        assert_eq!(ratios[0].long_short_ratio, Decimal::new(14_342, 4));
+        assert_eq!(ratios[0].long_account, Decimal::new(5_891, 4));
+        assert_eq!(ratios[0].short_account, Decimal::new(4_108, 4));
+        assert_eq!(ratios[1].timestamp, 1_583_139_900_000);
+    }
+
+    #[test]
+    async fn taker_long_short_ratio() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_ratio = server
+            .mock("GET", "/futures/data/takerlongshortRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^period=1h&startTime=1585614900000&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/taker_long_short_ratio.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let ratios = market
+            .taker_long_short_ratio("BTCUSDT", "1h", None, 1_585_614_900_000, None)
+            .await
+            .unwrap();
+        mock_ratio.assert();
+
+        assert_eq!(ratios.len(), 1);
+        assert_eq!(ratios[0].buy_sell_ratio, Decimal::new(15_586, 4));
+        assert_eq!(ratios[0].buy_vol, Decimal::new(387_330, 3));
+        assert_eq!(ratios[0].sell_vol, Decimal::new(248_503, 3));
+    }
+
+    #[test]
+    async fn get_lvt_klines() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_klines = server
+            .mock("GET", "/fapi/v1/lvtKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^interval=1h&limit=1&symbol=BTCDOWN$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/lvt_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let klines = market
+            .get_lvt_klines("BTCDOWN", "1h", 1, None, None)
+            .await
+            .unwrap();
+        mock_klines.assert();
+
+        let KlineSummaries::AllKlineSummaries(klines) = klines;
+        assert_eq!(klines[0].close, "5.99403551");
+        assert_eq!(klines[0].volume, "2.28602984");
+        assert_eq!(klines[0].number_of_trades, 6209);
+
+        assert!(market
+            .for_product(FuturesProduct::COINM)
+            .get_lvt_klines("BTCDOWN", "1h", 1, None, None)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn get_index_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_index_info = server
+            .mock("GET", "/fapi/v1/indexInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=DEFIUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/index_info.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let index_info = market
+            .get_index_info(Some("DEFIUSDT".into()))
+            .await
+            .unwrap();
+        mock_index_info.assert();
+
+        assert_eq!(index_info.len(), 1);
+        assert_eq!(index_info[0].symbol, "DEFIUSDT");
+        let components = &index_info[0].base_asset_list;
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].base_asset, "BAL");
+        assert_eq!(
+            components[0].weight_in_quantity,
+            Decimal::new(104_406_228, 8)
+        );
+        assert_eq!(
+            components[1].weight_in_percentage,
+            Decimal::new(3_935_200, 8)
+        );
+
+        assert!(market
+            .for_product(FuturesProduct::COINM)
+            .get_index_info(None)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn get_funding_rate_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_funding_rate = server
+            .mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^limit=2&startTime=1570608000000&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/funding_rate.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let funding_rates = market
+            .get_funding_rate_history("BTCUSDT", 1_570_608_000_000, None, 2)
+            .await
+            .unwrap();
+        mock_funding_rate.assert();
+
+        assert_eq!(funding_rates.len(), 2);
+        assert_eq!(funding_rates[0].symbol, "BTCUSDT");
+        assert_eq!(funding_rates[0].funding_time, 1_570_608_000_000);
+        assert_eq!(funding_rates[0].funding_rate, Decimal::new(-3_000, 7));
+        assert_eq!(funding_rates[1].funding_rate, Decimal::new(1_000, 7));
+    }
+
+    #[test]
+    async fn get_custom_depth() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_depth = server
+            .mock("GET", "/fapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=500&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/coin_m_depth.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let order_book = market
+            .get_custom_depth("BTCUSDT", FuturesDepthLimit::Limit500)
+            .await
+            .unwrap();
+        mock_depth.assert();
+
+        assert_eq!(order_book.last_update_id, 16_769_853);
+    }
 }
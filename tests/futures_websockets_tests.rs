@@ -0,0 +1,160 @@
+use binance::config::*;
+use binance::futures::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use float_cmp::*;
+    use futures_util::SinkExt;
+    use tokio::net::TcpListener;
+    use tokio::test;
+    use tokio_tungstenite::tungstenite::handshake::server::Request;
+    use tokio_tungstenite::tungstenite::handshake::server::Response;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+
+    /// Serve `payload` as a single text message to the first websocket client.
+    async fn serve(payload: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+        });
+        format!("ws://{addr}")
+    }
+
+    #[test]
+    async fn contract_info() {
+        let endpoint = serve(include_str!("mocks/futures/websockets/contract_info.json")).await;
+        let config = Config::default().set_futures_ws_endpoint(endpoint);
+        let mut web_socket =
+            WebSockets::connect_with_config(&FuturesMarket::USDM, "!contractInfo", &config)
+                .await
+                .unwrap();
+
+        let event = match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::ContractInfo(event)) => event,
+            event => panic!("unexpected event {:?}", event),
+        };
+
+        assert_eq!(event.event_type, "contractInfo");
+        assert_eq!(event.event_time, 1_669_356_423_908);
+        assert_eq!(event.symbol, "IOTAUSDT");
+        assert_eq!(event.pair, "IOTAUSDT");
+        assert_eq!(event.contract_type, "PERPETUAL");
+        assert_eq!(event.delivery_date, 4_133_404_800_000);
+        assert_eq!(event.onboard_date, 1_569_398_400_000);
+        assert_eq!(event.contract_status, "TRADING");
+        assert_eq!(event.brackets.len(), 2);
+        assert_eq!(event.brackets[1].bracket, 2);
+        assert!(approx_eq!(
+            f64,
+            event.brackets[1].notional_floor,
+            5000.0,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            event.brackets[1].notional_cap,
+            25000.0,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            event.brackets[1].maint_margin_ratio,
+            0.025,
+            ulps = 2
+        ));
+        assert!(approx_eq!(f64, event.brackets[1].cum, 75.0, ulps = 2));
+        assert_eq!(event.brackets[1].min_leverage, 11);
+        assert_eq!(event.brackets[1].max_leverage, 20);
+    }
+
+    // The handshake callback signature is set by tungstenite.
+    #[allow(clippy::result_large_err)]
+    #[test]
+    async fn coinm_endpoint() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let path = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut path = String::new();
+            let _websocket = tokio_tungstenite::accept_hdr_async(
+                stream,
+                |request: &Request, response: Response| {
+                    path = request.uri().path().to_owned();
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+            path
+        });
+        let config = Config::default()
+            .set_futures_ws_endpoint("ws://127.0.0.1:1/ws")
+            .set_delivery_ws_endpoint(endpoint);
+
+        WebSockets::connect_with_config(&FuturesMarket::COINM, "btcusd_perp@markPrice", &config)
+            .await
+            .unwrap();
+
+        assert_eq!(path.await.unwrap(), "/ws/btcusd_perp@markPrice");
+    }
+
+    /// Accept a websocket client on `listener`, returning the path and
+    /// query it connected to.
+    #[allow(clippy::result_large_err)]
+    async fn accept_uri(listener: TcpListener) -> String {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut uri = String::new();
+        let _websocket =
+            tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response: Response| {
+                uri = request.uri().to_string();
+                Ok(response)
+            })
+            .await
+            .unwrap();
+        uri
+    }
+
+    #[test]
+    async fn vanilla_endpoint() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let uri = tokio::spawn(accept_uri(listener));
+        let config = Config::default().set_vanilla_ws_endpoint(endpoint);
+
+        WebSockets::connect_with_config(
+            &FuturesMarket::Vanilla,
+            "BTC-200630-9000-P@ticker",
+            &config,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(uri.await.unwrap(), "/ws/BTC-200630-9000-P@ticker");
+    }
+
+    #[test]
+    async fn multiple_streams_endpoint() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let uri = tokio::spawn(accept_uri(listener));
+        let config = Config::default().set_futures_ws_endpoint(endpoint);
+
+        WebSockets::connect_multiple_streams_with_config(
+            &FuturesMarket::USDM,
+            &["btcusdt@aggTrade".into(), "btcusdt@markPrice".into()],
+            &config,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            uri.await.unwrap(),
+            "/stream?streams=btcusdt@aggTrade/btcusdt@markPrice"
+        );
+    }
+}
@@ -1,9 +1,17 @@
 use binance::config::*;
 use binance::model::*;
 use binance::spot::general::*;
+use rust_decimal::Decimal;
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+    use std::convert::TryFrom;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    use binance::spot::account::Account;
+    use binance::spot::account::OrderType;
     use float_cmp::*;
     use tokio::test;
 
@@ -46,6 +54,45 @@ mod tests {
         assert_eq!(server_time.server_time, 1_614_694_549_948);
     }
 
+    #[test]
+    async fn sync_time() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let now = i64::try_from(now).unwrap();
+        let hour = 3_600_000;
+        let mut server = mockito::Server::new_async().await;
+        let mock_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(format!("{{\"serverTime\":{}}}", now + hour))
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_time_offset(-hour);
+        let general = General::new_with_config(None, None, &config).unwrap();
+        let account = Account::new_with_config(None, None, &config).unwrap();
+
+        let offset = general.sync_time().await.unwrap();
+        mock_time.assert();
+
+        // The measured offset replaces the one of the configuration, for
+        // every client built from it.
+        assert!((3_595_000..=3_600_000).contains(&offset), "{}", offset);
+        assert_eq!(config.time_offset.get(), offset);
+        assert_eq!(account.client.time_offset().get(), offset);
+        assert_eq!(Config::default().time_offset.get(), 0);
+
+        let request = account
+            .client
+            .build_signed_request(BTreeMap::new(), 0)
+            .unwrap();
+        let timestamp: i64 = request.strip_prefix("timestamp=").unwrap().parse().unwrap();
+        assert!(timestamp >= now + hour - 5_000);
+    }
+
     #[test]
     async fn exchange_info() {
         let mut server = mockito::Server::new_async().await;
@@ -165,4 +212,186 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    async fn allowed_order_types() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut general = General::new_with_config(None, None, &config).unwrap();
+        general.update_cache().await.unwrap();
+
+        let symbol = general.get_symbol_info("BNBBTC").unwrap();
+        mock_exchange_info.assert();
+
+        let order_types = symbol.allowed_order_types();
+        assert_eq!(order_types.len(), 5);
+        assert!(order_types.contains(&OrderType::LimitMaker));
+        assert!(order_types.contains(&OrderType::TakeProfitLimit));
+        assert!(!order_types.contains(&OrderType::StopLoss));
+
+        assert!(symbol.supports_order_type(OrderType::Limit));
+        assert!(symbol.supports_order_type(OrderType::Market));
+        assert!(symbol.supports_order_type(OrderType::StopLossLimit));
+
+        let mut symbol = symbol.clone();
+        symbol.order_types = vec!["LIMIT".into(), "OTO".into()];
+        assert!(!symbol.supports_order_type(OrderType::StopLossLimit));
+        assert!(symbol
+            .allowed_order_types()
+            .contains(&OrderType::Other("OTO".into())));
+    }
+
+    #[test]
+    async fn filter_table() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut general = General::new_with_config(None, None, &config).unwrap();
+        assert!(general.filter_table().is_err());
+
+        general.update_cache().await.unwrap();
+        let table = general.filter_table().unwrap();
+        mock_exchange_info.assert();
+
+        assert_eq!(table.len(), 3);
+        let filters = &table["LTCBTC"];
+        assert_eq!(filters.tick_size, Some(Decimal::new(1, 6)));
+        assert_eq!(filters.step_size, Some(Decimal::new(1, 2)));
+        assert_eq!(filters.min_notional, Some(Decimal::new(1, 4)));
+        assert_eq!(filters.min_qty, Some(Decimal::new(1, 2)));
+        assert_eq!(filters.max_qty, Some(Decimal::new(100_000, 0)));
+    }
+
+    #[test]
+    async fn diff_symbols() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_previous_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info_previous.json")
+            .expect(1)
+            .create();
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut general = General::new_with_config(None, None, &config).unwrap();
+        general.update_cache().await.unwrap();
+        let previous = general.exchange_info().unwrap().0;
+        general.update_cache().await.unwrap();
+
+        let (added, removed) = general.diff_symbols(&previous).unwrap();
+        mock_previous_exchange_info.assert();
+        mock_exchange_info.assert();
+
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].symbol, "BNBBTC");
+        assert!(!added[0].filters.is_empty());
+        assert_eq!(removed, vec!["XRPBTC"]);
+    }
+
+    #[test]
+    async fn save_and_load_cache() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut general = General::new_with_config(None, None, &config).unwrap();
+        general.update_cache().await.unwrap();
+        mock_exchange_info.assert();
+
+        let path =
+            std::env::temp_dir().join(format!("binance-exchange-info-{}.json", std::process::id()));
+        general.save_cache(&path).unwrap();
+
+        let mut loaded = General::new_with_config(None, None, &config).unwrap();
+        assert!(loaded.load_cache(&path).unwrap());
+        assert!(loaded.has_cache());
+        assert_eq!(
+            loaded.exchange_info().unwrap().0.symbols,
+            general.exchange_info().unwrap().0.symbols
+        );
+        assert_eq!(loaded.get_symbol_info("ltcbtc").unwrap().symbol, "LTCBTC");
+
+        let mut saved: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        saved["lastUpdate"] = 0.into();
+        std::fs::write(&path, saved.to_string()).unwrap();
+
+        assert!(!loaded.load_cache(&path).unwrap());
+        assert!(!loaded.has_cache());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    async fn save_empty_cache() {
+        let general = General::new(None, None).unwrap();
+        let path = std::env::temp_dir().join("binance-exchange-info-empty.json");
+
+        assert!(general.save_cache(&path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    async fn update_cache_incremental() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_previous_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info_previous.json")
+            .expect(1)
+            .create();
+        let mock_changed_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info_changed.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut general = General::new_with_config(None, None, &config).unwrap();
+        let update = general.update_cache_incremental().await.unwrap();
+        assert_eq!(update.added, vec!["ETHBTC", "LTCBTC", "XRPBTC"]);
+        assert!(general.filter_table().unwrap().contains_key("XRPBTC"));
+
+        let update = general.update_cache_incremental().await.unwrap();
+        assert_eq!(update.added, vec!["BNBBTC"]);
+        assert_eq!(update.changed, vec!["LTCBTC"]);
+        assert_eq!(update.removed, vec!["XRPBTC"]);
+
+        let update = general.update_cache_incremental().await.unwrap();
+        assert!(update.is_empty());
+        mock_previous_exchange_info.assert();
+        mock_changed_exchange_info.assert();
+
+        assert_eq!(general.get_symbol_info("LTCBTC").unwrap().status, "BREAK");
+        assert_eq!(general.get_symbol_info("bnbbtc").unwrap().symbol, "BNBBTC");
+        assert!(general.get_symbol_info("XRPBTC").is_err());
+        assert_eq!(general.exchange_info().unwrap().0.symbols.len(), 3);
+        let table = general.filter_table().unwrap();
+        assert_eq!(table.len(), 3);
+        assert!(table.contains_key("BNBBTC"));
+        assert!(!table.contains_key("XRPBTC"));
+    }
 }
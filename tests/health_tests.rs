@@ -0,0 +1,44 @@
+use binance::config::*;
+
+#[cfg(test)]
+mod tests {
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    async fn health_check() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_spot_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create();
+        let mock_futures_ping = server
+            .mock("GET", "/fapi/v1/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create();
+        let mock_delivery_ping = server
+            .mock("GET", "/dapi/v1/ping")
+            .with_status(503)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_futures_rest_api_endpoint(server.url())
+            .set_delivery_rest_api_endpoint(server.url());
+        let report = binance::health::health_check(&config).await;
+
+        mock_spot_ping.assert();
+        mock_futures_ping.assert();
+        mock_delivery_ping.assert();
+
+        assert!(report.spot.is_ok());
+        assert!(report.futures_um.is_ok());
+        assert!(report.futures_cm.is_err());
+        assert!(!report.is_healthy());
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "kline-decimal")]
+
+use binance::config::*;
+use binance::model::*;
+use binance::spot::market::*;
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+    use rust_decimal::Decimal;
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    async fn kline_decimal_values() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_klines = server
+            .mock("GET", "/api/v3/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("interval=5m&limit=10&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_klines.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let klines = market
+            .get_klines("LTCBTC", "5m", 10, None, None)
+            .await
+            .unwrap();
+        mock_get_klines.assert();
+
+        let KlineSummaries::AllKlineSummaries(klines) = klines;
+        let kline = &klines[0];
+
+        assert_eq!(kline.open_decimal, Decimal::new(1_634_790, 8));
+        assert_eq!(kline.high_decimal, Decimal::new(80_000_000, 8));
+        assert_eq!(kline.low_decimal, Decimal::new(1_575_800, 8));
+        assert_eq!(kline.close_decimal, Decimal::new(1_577_100, 8));
+        assert_eq!(kline.volume_decimal, Decimal::new(14_897_611_427_815, 8));
+        assert_eq!(
+            kline.quote_asset_volume_decimal,
+            Decimal::new(243_419_055_334, 8)
+        );
+        assert_eq!(
+            kline.taker_buy_base_asset_volume_decimal,
+            Decimal::new(175_687_402_397, 8)
+        );
+        assert_eq!(
+            kline.taker_buy_quote_asset_volume_decimal,
+            Decimal::new(2_846_694_368, 8)
+        );
+        // The exact string scale is preserved.
+        assert_eq!(kline.volume_decimal.to_string(), kline.volume);
+    }
+
+    #[test]
+    async fn kline_decimal_invalid_value() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_klines = server
+            .mock("GET", "/api/v3/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("interval=5m&limit=10&symbol=LTCBTC".into()))
+            .with_body(
+                r#"[[1499040000000,"not a number","0","0","0","0",1499644799999,"0",0,"0","0","0"]]"#,
+            )
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let klines = market.get_klines("LTCBTC", "5m", 10, None, None).await;
+        mock_get_klines.assert();
+
+        assert!(klines.is_err());
+    }
+}
@@ -8,6 +8,7 @@ mod tests {
     use float_cmp::*;
     use mockito::Matcher;
     use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal::Decimal;
     use tokio::test;
 
     use super::*;
@@ -38,6 +39,117 @@ mod tests {
         );
     }
 
+    fn order_book(bids: &[(i64, i64)], asks: &[(i64, i64)]) -> binance::spot::model::OrderBook {
+        binance::spot::model::OrderBook {
+            last_update_id: 1,
+            bids: bids
+                .iter()
+                .map(|&(price, qty)| Bids::new(Decimal::from(price), Decimal::from(qty)))
+                .collect(),
+            asks: asks
+                .iter()
+                .map(|&(price, qty)| Asks {
+                    price: Decimal::from(price),
+                    qty: Decimal::from(qty),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    async fn weighted_mid() {
+        let book = order_book(&[(10, 1), (9, 3)], &[(11, 3), (12, 1)]);
+
+        assert_eq!(book.weighted_mid(1), Some(Decimal::new(1075, 2)));
+        assert_eq!(book.weighted_mid(2), Some(Decimal::new(1025, 2)));
+        assert_eq!(book.weighted_mid(10), Some(Decimal::new(1025, 2)));
+        assert_eq!(book.weighted_mid(0), None);
+        assert_eq!(order_book(&[(10, 1)], &[]).weighted_mid(1), None);
+    }
+
+    #[test]
+    async fn microprice() {
+        let book = order_book(&[(10, 1), (9, 3)], &[(11, 3), (12, 1)]);
+
+        assert_eq!(book.microprice(), Some(Decimal::new(1025, 2)));
+        assert_eq!(order_book(&[(10, 0)], &[(11, 0)]).microprice(), None);
+        assert_eq!(order_book(&[], &[(11, 3)]).microprice(), None);
+    }
+
+    #[test]
+    async fn order_book_levels() {
+        let book = order_book(&[(10, 1), (9, 3)], &[(11, 2)]);
+
+        let bids: Vec<Level> = book.bid_levels().collect();
+        assert_eq!(
+            bids,
+            vec![
+                Level {
+                    price: Decimal::from(10),
+                    qty: Decimal::from(1)
+                },
+                Level {
+                    price: Decimal::from(9),
+                    qty: Decimal::from(3)
+                },
+            ]
+        );
+        let asks: Vec<Level> = book.ask_levels().collect();
+        assert_eq!(
+            asks,
+            vec![Level {
+                price: Decimal::from(11),
+                qty: Decimal::from(2)
+            }]
+        );
+    }
+
+    #[test]
+    async fn validate_order_book() {
+        assert!(order_book(&[(10, 1), (9, 3)], &[(11, 3), (12, 1)])
+            .validate()
+            .is_ok());
+        assert!(order_book(&[], &[]).validate().is_ok());
+    }
+
+    #[test]
+    async fn validate_unsorted_bids() {
+        let err = order_book(&[(9, 1), (10, 3)], &[(11, 3)])
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Unsorted bid price 10 after 9");
+    }
+
+    #[test]
+    async fn validate_unsorted_asks() {
+        let err = order_book(&[(10, 1)], &[(12, 3), (11, 1)])
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Unsorted ask price 11 after 12");
+    }
+
+    #[test]
+    async fn validate_duplicate_price() {
+        let err = order_book(&[(10, 1), (10, 3)], &[(11, 3)])
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate bid price 10");
+
+        let err = order_book(&[(10, 1)], &[(11, 3), (11, 1)])
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Duplicate ask price 11");
+    }
+
+    #[test]
+    async fn validate_quantity() {
+        let err = order_book(&[(10, 0)], &[(11, 3)]).validate().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid bid quantity 0 at price 10");
+
+        let err = order_book(&[(10, 1)], &[(11, -3)]).validate().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid ask quantity -3 at price 11");
+    }
+
     #[test]
     async fn get_custom_depth() {
         let mut server = mockito::Server::new_async().await;
@@ -64,6 +176,28 @@ mod tests {
         );
     }
 
+    #[test]
+    async fn get_custom_depth_typed_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_custom_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=5000&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_depth.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let order_book = market
+            .get_custom_depth("LTCBTC", SpotDepthLimit::Limit5000)
+            .await
+            .unwrap();
+        mock_get_custom_depth.assert();
+
+        assert_eq!(order_book.last_update_id, 1_027_024);
+    }
+
     #[test]
     async fn get_all_prices() {
         let mut server = mockito::Server::new_async().await;
@@ -112,6 +246,213 @@ mod tests {
         assert!(approx_eq!(f64, symbol.price, 4.000_002_00, ulps = 2));
     }
 
+    #[test]
+    async fn get_rolling_window_stats() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_stats = server
+            .mock("GET", "/api/v3/ticker")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbol=BNBBTC&type=MINI&windowSize=4h$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_rolling_window_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let stats = market
+            .get_rolling_window_stats("BNBBTC", "4h", TickerType::Mini)
+            .await
+            .unwrap();
+        mock_stats.assert();
+
+        assert_eq!(stats.symbol, "BNBBTC");
+        assert!(stats.price_change.is_none());
+        assert!(approx_eq!(f64, stats.last_price, 0.0129, ulps = 2));
+        assert!(approx_eq!(f64, stats.quote_volume, 80.276_125, ulps = 2));
+        assert_eq!(stats.count, 2000);
+    }
+
+    #[test]
+    async fn get_prices() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_prices = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbols=%5B%22LTCBTC%22%2C%22ETHBTC%22%5D$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_all_prices.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let prices = market
+            .get_prices(&["LTCBTC".into(), "ETHBTC".into()])
+            .await
+            .unwrap();
+        mock_get_prices.assert();
+
+        assert_eq!(prices[0].symbol, "LTCBTC");
+        assert_eq!(prices[1].symbol, "ETHBTC");
+        assert!(market.get_prices(&[]).await.is_err());
+    }
+
+    #[test]
+    async fn get_24h_price_stats_multi() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_price_stats = server
+            .mock("GET", "/api/v3/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbols=%5B%22BNBBTC%22%5D$".into()))
+            .with_body_from_file("tests/mocks/market/get_all_24h_price_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let price_stats = market
+            .get_24h_price_stats_multi(&["BNBBTC".into()])
+            .await
+            .unwrap();
+        mock_get_price_stats.assert();
+
+        assert_eq!(price_stats.len(), 1);
+        assert_eq!(price_stats[0].symbol, "BNBBTC");
+    }
+
+    #[test]
+    async fn get_trades() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_trades = server
+            .mock("GET", "/api/v3/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=1&symbol=LTCBTC$".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let trades = market.get_trades("LTCBTC", 1).await.unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, 28457);
+        assert!(approx_eq!(f64, trades[0].price, 4.000_001, ulps = 2));
+        assert!(approx_eq!(f64, trades[0].qty, 12.0, ulps = 2));
+        assert!(approx_eq!(f64, trades[0].quote_qty, 48.000_012, ulps = 2));
+        assert_eq!(trades[0].time, 1_499_865_549_590);
+        assert!(trades[0].is_buyer_maker);
+        assert!(trades[0].is_best_match);
+    }
+
+    #[test]
+    async fn get_historical_trades() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_trades = server
+            .mock("GET", "/api/v3/historicalTrades")
+            .match_header("x-mbx-apikey", "api-key")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^fromId=28457&symbol=LTCBTC$".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(Some("api-key".into()), None, &config).unwrap();
+
+        let trades = market
+            .get_historical_trades("LTCBTC", None, 28457)
+            .await
+            .unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades[0].id, 28457);
+    }
+
+    #[test]
+    async fn get_price_single_item_list() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_price = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body("[{\"symbol\": \"LTCBTC\", \"price\": \"4.00000200\"}]")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let symbol = market.get_price("LTCBTC").await.unwrap();
+        mock_get_price.assert();
+
+        assert_eq!(symbol.symbol, "LTCBTC");
+        assert!(approx_eq!(f64, symbol.price, 4.000_002_00, ulps = 2));
+    }
+
+    #[test]
+    async fn get_price_many_items() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_price = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_all_prices.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let err = market.get_price("LTCBTC").await.unwrap_err();
+        mock_get_price.assert();
+
+        assert_eq!(
+            err.to_string(),
+            "Expected a single SymbolPrice, got a list of 2"
+        );
+    }
+
+    #[test]
+    async fn warm_snapshot() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_price = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=LTCBTC$".into()))
+            .with_body_from_file("tests/mocks/market/get_price.json")
+            .create();
+        let mock_get_price_failure = server
+            .mock("GET", "/api/v3/ticker/price")
+            .match_query(Matcher::Regex("^symbol=ETHBTC$".into()))
+            .with_status(500)
+            .create();
+        let mock_get_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=5&symbol=LTCBTC$".into()))
+            .with_body_from_file("tests/mocks/market/get_depth.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let symbols = vec!["LTCBTC".to_string(), "ETHBTC".to_string()];
+        let snapshot = market
+            .warm_snapshot(&symbols, SpotDepthLimit::Limit5, 4)
+            .await;
+        mock_get_price.assert();
+        mock_get_price_failure.assert();
+        mock_get_depth.assert();
+
+        assert_eq!(snapshot.len(), 2);
+        let (price, order_book) = snapshot["LTCBTC"].as_ref().unwrap();
+        assert_eq!(price.symbol, "LTCBTC");
+        assert_eq!(order_book.last_update_id, 1_027_024);
+        assert!(snapshot["ETHBTC"].is_err());
+    }
+
     #[test]
     async fn get_average_price() {
         let mut server = mockito::Server::new_async().await;
@@ -130,6 +471,26 @@ mod tests {
 
         assert_eq!(symbol.mins, 5);
         assert!(approx_eq!(f64, symbol.price, 9.357_518_34, ulps = 2));
+        assert_eq!(symbol.close_time, None);
+    }
+
+    #[test]
+    async fn get_average_price_close_time() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_average_price = server
+            .mock("GET", "/api/v3/avgPrice")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body(r#"{"mins":5,"price":"9.35751834","closeTime":1694061154503}"#)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let symbol = market.get_average_price("LTCBTC").await.unwrap();
+        mock_get_average_price.assert();
+
+        assert_eq!(symbol.close_time, Some(1_694_061_154_503));
     }
 
     #[test]
@@ -395,4 +756,45 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    async fn relative_volume() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_get_24h_price_stats = server
+            .mock("GET", "/api/v3/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BNBBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_24h_price_stats.json")
+            .create();
+        let mock_get_klines = server
+            .mock("GET", "/api/v3/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("interval=1d&limit=4&symbol=BNBBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_daily_klines.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market = Market::new_with_config(None, None, &config).unwrap();
+
+        let relative_volume = market.relative_volume("BNBBTC", 3).await.unwrap();
+        mock_get_24h_price_stats.assert();
+        mock_get_klines.assert();
+
+        // 8913.3 against an average of 4000 over the three completed days.
+        assert_eq!(relative_volume.to_string(), "2.228325");
+
+        assert!(market.relative_volume("BNBBTC", 0).await.is_err());
+    }
+
+    #[test]
+    async fn depth_limits() {
+        assert_eq!(SpotDepthLimit::Limit5000.value(), 5000);
+        assert_eq!(SpotDepthLimit::from(500), SpotDepthLimit::Limit500);
+        assert_eq!(SpotDepthLimit::from(7), SpotDepthLimit::Custom(7));
+        assert_eq!(SpotDepthLimit::Custom(7).value(), 7);
+        assert_eq!(
+            binance::futures::market::FuturesDepthLimit::from(5000),
+            binance::futures::market::FuturesDepthLimit::Custom(5000)
+        );
+    }
 }
@@ -0,0 +1,122 @@
+use binance::errors::ErrorKind;
+use binance::model::DepthOrderBookEvent;
+use binance::model::Level;
+use binance::orderbook::OrderBookManager;
+use binance::spot::model::OrderBook;
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::*;
+
+    fn snapshot(last_update_id: u64) -> OrderBook {
+        serde_json::from_value(serde_json::json!({
+            "lastUpdateId": last_update_id,
+            "bids": [["100.0", "1.0"], ["99.0", "2.0"]],
+            "asks": [["101.0", "1.5"], ["102.0", "3.0"]]
+        }))
+        .unwrap()
+    }
+
+    fn diff(
+        first_update_id: u64,
+        final_update_id: u64,
+        bids: &[(&str, &str)],
+        asks: &[(&str, &str)],
+    ) -> DepthOrderBookEvent {
+        serde_json::from_value(serde_json::json!({
+            "e": "depthUpdate",
+            "E": 1_672_515_782_136_u64,
+            "s": "BNBBTC",
+            "U": first_update_id,
+            "u": final_update_id,
+            "b": bids,
+            "a": asks
+        }))
+        .unwrap()
+    }
+
+    fn level(price: i64, qty: i64) -> Level {
+        Level {
+            price: Decimal::new(price, 1),
+            qty: Decimal::new(qty, 1),
+        }
+    }
+
+    #[test]
+    fn snapshot_and_diffs() {
+        let mut book = OrderBookManager::new();
+
+        // Buffered until the snapshot, then dropped as already contained.
+        book.apply_diff(&diff(95, 100, &[("100.0", "5.0")], &[]))
+            .unwrap();
+        // Buffered, then applied as it straddles the snapshot.
+        book.apply_diff(&diff(99, 102, &[("100.0", "0.5")], &[("103.0", "1.0")]))
+            .unwrap();
+        assert_eq!(book.last_update_id(), None);
+
+        book.apply_snapshot(snapshot(100)).unwrap();
+        assert_eq!(book.last_update_id(), Some(102));
+        assert_eq!(book.best_bid(), Some(level(1000, 5)));
+
+        book.apply_diff(&diff(
+            103,
+            105,
+            &[("100.0", "0"), ("99.5", "4.0")],
+            &[("101.0", "0")],
+        ))
+        .unwrap();
+
+        assert_eq!(book.last_update_id(), Some(105));
+        assert_eq!(
+            book.bids().collect::<Vec<_>>(),
+            vec![level(995, 40), level(990, 20)]
+        );
+        assert_eq!(
+            book.asks().collect::<Vec<_>>(),
+            vec![level(1020, 30), level(1030, 10)]
+        );
+        assert_eq!(book.best_ask(), Some(level(1020, 30)));
+
+        // Stale events are ignored.
+        book.apply_diff(&diff(104, 105, &[("98.0", "1.0")], &[]))
+            .unwrap();
+        assert_eq!(book.bids().count(), 2);
+    }
+
+    #[test]
+    fn gap_requires_resync() {
+        let mut book = OrderBookManager::new();
+        book.apply_snapshot(snapshot(100)).unwrap();
+
+        let error = book
+            .apply_diff(&diff(103, 104, &[("100.0", "5.0")], &[]))
+            .unwrap_err();
+        match error.kind() {
+            ErrorKind::OrderBookGap(last_update_id, first_update_id) => {
+                assert_eq!(*last_update_id, 100);
+                assert_eq!(*first_update_id, 103);
+            }
+            kind => panic!("{:?}", kind),
+        }
+        assert_eq!(book.last_update_id(), None);
+        assert_eq!(book.best_bid(), None);
+
+        // Events are buffered again until the next snapshot.
+        book.apply_diff(&diff(105, 106, &[("100.0", "5.0")], &[]))
+            .unwrap();
+        book.apply_snapshot(snapshot(104)).unwrap();
+        assert_eq!(book.last_update_id(), Some(106));
+        assert_eq!(book.best_bid(), Some(level(1000, 50)));
+    }
+
+    #[test]
+    fn gap_in_buffered_events() {
+        let mut book = OrderBookManager::new();
+        book.apply_diff(&diff(110, 112, &[], &[])).unwrap();
+
+        assert!(book.apply_snapshot(snapshot(100)).is_err());
+        assert_eq!(book.last_update_id(), None);
+    }
+}
@@ -0,0 +1,346 @@
+use binance::config::*;
+use binance::savings::*;
+
+#[cfg(test)]
+mod tests {
+    use binance::model::UniversalTransferType;
+    use float_cmp::*;
+    use mockito::Matcher;
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    async fn dust_assets() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_dust_assets = server
+            .mock("POST", "/sapi/v1/asset/dust-btc")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_assets.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let _ = env_logger::try_init();
+        let dust = savings.dust_assets().await.unwrap();
+
+        mock_dust_assets.assert();
+
+        assert_eq!(dust.details.len(), 1);
+        let ada = &dust.details[0];
+        assert_eq!(ada.asset, "ADA");
+        assert_eq!(ada.asset_full_name, "ADA");
+        assert!(approx_eq!(f64, ada.amount_free, 6.21, ulps = 2));
+        assert!(approx_eq!(f64, ada.to_btc, 0.000_168_48, ulps = 2));
+        assert!(approx_eq!(f64, ada.to_bnb, 0.017_773_02, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            ada.to_bnb_off_exchange,
+            0.017_417_56,
+            ulps = 2
+        ));
+        assert!(approx_eq!(f64, ada.exchange, 0.000_355_46, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            dust.total_transfer_btc,
+            0.000_168_48,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            dust.total_transfer_bnb,
+            0.017_773_02,
+            ulps = 2
+        ));
+        assert!(approx_eq!(f64, dust.dribblet_percentage, 0.02, ulps = 2));
+    }
+
+    #[test]
+    async fn dust_transfer() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_dust_transfer = server
+            .mock("POST", "/sapi/v1/asset/dust")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=ETH&asset=LTC&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let result = savings
+            .dust_transfer(&["ETH".into(), "LTC".into()])
+            .await
+            .unwrap();
+
+        mock_dust_transfer.assert();
+
+        assert!(approx_eq!(
+            f64,
+            result.total_service_charge,
+            0.021_025_42,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            result.total_transfered,
+            1.051_270_99,
+            ulps = 2
+        ));
+        assert_eq!(result.transfer_result.len(), 2);
+        let eth = &result.transfer_result[0];
+        assert_eq!(eth.from_asset, "ETH");
+        assert_eq!(eth.tran_id, 2_970_932_918);
+        assert_eq!(eth.operate_time, 1_563_368_549_307);
+        assert!(approx_eq!(f64, eth.amount, 0.03, ulps = 2));
+        assert!(approx_eq!(f64, eth.service_charge_amount, 0.005, ulps = 2));
+        assert!(approx_eq!(f64, eth.transfered_amount, 0.25, ulps = 2));
+    }
+
+    #[test]
+    async fn dust_transfer_without_assets() {
+        let savings = Savings::new(None, None).unwrap();
+
+        assert!(savings.dust_transfer(&[]).await.is_err());
+    }
+
+    #[test]
+    async fn withdraw() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_withdraw = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^address=bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23&amount=0.00000001&coin=BNB&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body("{\"id\": \"7213fea8e94b4a5593d507237e5a555b\"}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let id = savings
+            .withdraw(
+                "BNB",
+                "bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23",
+                0.000_000_01,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock_withdraw.assert();
+        assert_eq!(id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    async fn withdraw_with_network_and_tag() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_withdraw = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^address=bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23&addressTag=101&amount=1.5&coin=BNB&network=BNB&recvWindow=1234&timestamp=\\d+&withdrawOrderId=payout-1&signature=.*".into(),
+            ))
+            .with_body("{\"id\": \"7213fea8e94b4a5593d507237e5a555b\"}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        savings
+            .withdraw(
+                "BNB",
+                "bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23",
+                1.5,
+                Some("BNB".into()),
+                Some("101".into()),
+                Some("payout-1".into()),
+            )
+            .await
+            .unwrap();
+
+        mock_withdraw.assert();
+    }
+
+    #[test]
+    async fn deposit_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_deposit_history = server
+            .mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^coin=BNB&limit=10&recvWindow=1234&startTime=1661000000000&status=1&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/deposit_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let deposits = savings
+            .deposit_history(
+                Some("BNB".into()),
+                Some(1),
+                Some(1_661_000_000_000),
+                None,
+                None,
+                Some(10),
+            )
+            .await
+            .unwrap();
+
+        mock_deposit_history.assert();
+
+        assert_eq!(deposits.len(), 1);
+        let deposit = &deposits[0];
+        assert!(approx_eq!(f64, deposit.amount, 0.001, ulps = 2));
+        assert_eq!(deposit.coin, "BNB");
+        assert_eq!(deposit.network, "BNB");
+        assert_eq!(deposit.status, 1);
+        assert_eq!(deposit.address_tag, "101764890");
+        assert_eq!(
+            deposit.tx_id,
+            "98A3EA560C6B3336D348B6C83F0F95ECE4F1F5919E94BD006E5BF3BF264FACFC"
+        );
+        assert_eq!(deposit.insert_time, 1_661_493_146_000);
+    }
+
+    #[test]
+    async fn withdraw_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_withdraw_history = server
+            .mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^offset=1000&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let withdrawals = savings
+            .withdraw_history(None, None, None, None, Some(1000), None)
+            .await
+            .unwrap();
+
+        mock_withdraw_history.assert();
+
+        assert_eq!(withdrawals.len(), 1);
+        let withdrawal = &withdrawals[0];
+        assert_eq!(withdrawal.id, "b6ae22b3aa844210a7041aee7589627c");
+        assert!(approx_eq!(f64, withdrawal.amount, 8.91, ulps = 2));
+        assert!(approx_eq!(f64, withdrawal.transaction_fee, 0.004, ulps = 2));
+        assert_eq!(withdrawal.coin, "USDT");
+        assert_eq!(withdrawal.status, 6);
+        assert_eq!(withdrawal.network, "ETH");
+        assert_eq!(withdrawal.apply_time, "2019-10-12 11:12:02");
+        assert_eq!(
+            withdrawal.withdraw_order_id.as_deref(),
+            Some("WITHDRAWtest123")
+        );
+    }
+
+    #[test]
+    async fn universal_transfer() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_transfer = server
+            .mock("POST", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=12.5&asset=USDT&recvWindow=1234&timestamp=\\d+&type=MAIN_MARGIN&signature=.*".into(),
+            ))
+            .with_body("{\"tranId\": 13526853623}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let transfer = savings
+            .universal_transfer(UniversalTransferType::MainMargin, "USDT", 12.5)
+            .await
+            .unwrap();
+
+        mock_transfer.assert();
+        assert_eq!(transfer.tran_id, 13_526_853_623);
+    }
+
+    #[test]
+    async fn transfer_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_transfer_history = server
+            .mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^current=2&recvWindow=1234&size=100&timestamp=\\d+&type=MAIN_UMFUTURE&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/transfer_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let history = savings
+            .transfer_history(
+                UniversalTransferType::MainUmfuture,
+                None,
+                None,
+                Some(2),
+                Some(100),
+            )
+            .await
+            .unwrap();
+
+        mock_transfer_history.assert();
+
+        assert_eq!(history.total, 2);
+        assert_eq!(history.rows.len(), 2);
+        let transfer = &history.rows[0];
+        assert_eq!(transfer.asset, "USDT");
+        assert!(approx_eq!(f64, transfer.amount, 1.0, ulps = 2));
+        assert_eq!(transfer.transfer_type, UniversalTransferType::MainUmfuture);
+        assert_eq!(transfer.status, "CONFIRMED");
+        assert_eq!(transfer.tran_id, 11_415_955_596);
+        assert_eq!(transfer.timestamp, 1_544_433_328_000);
+    }
+
+    #[test]
+    async fn empty_transfer_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock_transfer_history = server
+            .mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body("{\"total\": 0}")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let savings = Savings::new_with_config(None, None, &config).unwrap();
+        let history = savings
+            .transfer_history(UniversalTransferType::FundingMain, None, None, None, None)
+            .await
+            .unwrap();
+
+        mock_transfer_history.assert();
+        assert_eq!(history.total, 0);
+        assert!(history.rows.is_empty());
+    }
+}
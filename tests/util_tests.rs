@@ -23,6 +23,35 @@ mod tests {
         assert_eq!(result, format!("recvWindow={}", 1234));
     }
 
+    #[test]
+    fn build_array_param_strings() {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert(
+            "symbols".into(),
+            build_array_param(&["BTCUSDT", "ETHUSDT", "BNBUSDT"]).unwrap(),
+        );
+        let result = build_request(parameters);
+        assert_eq!(
+            result,
+            "symbols=%5B%22BTCUSDT%22%2C%22ETHUSDT%22%2C%22BNBUSDT%22%5D"
+        );
+    }
+
+    #[test]
+    fn build_array_param_numbers() {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderIdList".into(), build_array_param(&[1, 2, 3]).unwrap());
+        parameters.insert("symbol".into(), "BTCUSDT".into());
+        let result = build_request(parameters);
+        assert_eq!(result, "orderIdList=%5B1%2C2%2C3%5D&symbol=BTCUSDT");
+    }
+
+    #[test]
+    fn build_array_param_empty() {
+        let values: [u64; 0] = [];
+        assert_eq!(build_array_param(&values).unwrap(), "%5B%5D");
+    }
+
     #[test]
     fn build_signed_request() {
         let now = SystemTime::now();
@@ -41,4 +70,18 @@ mod tests {
             format!("recvWindow={recv_window}&timestamp={timestamp}")
         );
     }
+
+    #[test]
+    fn build_signed_request_recv_window_bound() {
+        let now = SystemTime::now();
+
+        let result = binance::util::build_signed_request_custom(BTreeMap::new(), 60_000, now);
+        assert!(result.unwrap().starts_with("recvWindow=60000&timestamp="));
+
+        let result = binance::util::build_signed_request_custom(BTreeMap::new(), 60_001, now);
+        assert!(matches!(
+            result.unwrap_err().kind(),
+            binance::errors::ErrorKind::InvalidRecvWindow(60_001)
+        ));
+    }
 }
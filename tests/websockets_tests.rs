@@ -0,0 +1,818 @@
+use binance::model::*;
+use binance::spot::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::time::Duration;
+
+    use binance::config::Config;
+    use binance::errors::ErrorKind;
+    use binance::spot::stream::*;
+    use binance::spot::UserStream;
+    use binance::spot::UserStreamWebSocket;
+    use futures_util::SinkExt;
+    use futures_util::StreamExt;
+    use mockito::Matcher;
+    use rust_decimal::Decimal;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+
+    fn trade(event_time: u64, trade_id: u64) -> WebsocketEvent {
+        WebsocketEvent::Trade(TradeEvent {
+            event_type: "trade".into(),
+            event_time,
+            symbol: "BNBBTC".into(),
+            trade_id,
+            price: "0.001".into(),
+            qty: "100".into(),
+            buyer_order_id: 88,
+            seller_order_id: 50,
+            trade_order_time: event_time,
+            is_buyer_maker: true,
+            m_ignore: true,
+        })
+    }
+
+    fn trade_id(event: &WebsocketEvent) -> u64 {
+        match event {
+            WebsocketEvent::Trade(trade) => trade.trade_id,
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn reorder_buffer_sorts_by_event_time() {
+        let mut buffer = EventReorderBuffer::new(Duration::from_secs(3600));
+        buffer.push(trade(1_000, 2));
+        buffer.push(trade(900, 1));
+        buffer.push(trade(1_000, 3));
+
+        // Nothing is older than the window yet.
+        assert!(buffer.pop().is_none());
+        assert_eq!(buffer.len(), 3);
+
+        buffer.push(trade(3_600_950, 4));
+        assert_eq!(trade_id(&buffer.pop().unwrap()), 1);
+        assert!(buffer.pop().is_none());
+
+        buffer.push(trade(3_601_000, 5));
+        assert_eq!(trade_id(&buffer.pop().unwrap()), 2);
+        assert_eq!(trade_id(&buffer.pop().unwrap()), 3);
+        assert!(buffer.pop().is_none());
+
+        let rest: Vec<u64> = buffer.flush().iter().map(trade_id).collect();
+        assert_eq!(rest, vec![4, 5]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn reorder_buffer_releases_after_window() {
+        let mut buffer = EventReorderBuffer::new(Duration::from_millis(10));
+        buffer.push(trade(1_000, 1));
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(trade_id(&buffer.pop().unwrap()), 1);
+    }
+
+    #[test]
+    fn reorder_buffer_passes_untimed_events() {
+        let mut buffer = EventReorderBuffer::new(Duration::from_secs(3600));
+        buffer.push(trade(1_000, 1));
+        buffer.push(WebsocketEvent::BookTicker(BookTickerEvent {
+            update_id: 1,
+            symbol: "BNBBTC".into(),
+            best_bid: "0.001".into(),
+            best_bid_qty: "1".into(),
+            best_ask: "0.002".into(),
+            best_ask_qty: "1".into(),
+        }));
+
+        assert!(matches!(buffer.pop(), Some(WebsocketEvent::BookTicker(_))));
+        assert!(buffer.pop().is_none());
+        assert_eq!(buffer.len(), 1);
+    }
+
+    fn book_ticker() -> WebsocketEvent {
+        WebsocketEvent::BookTicker(BookTickerEvent {
+            update_id: 400_900_217,
+            symbol: "BNBUSDT".into(),
+            best_bid: "25.35190000".into(),
+            best_bid_qty: "31.21000000".into(),
+            best_ask: "25.36520000".into(),
+            best_ask_qty: "40.66000000".into(),
+        })
+    }
+
+    #[test]
+    fn stream_key_trade() {
+        assert_eq!(trade(1_000, 1).stream_key().unwrap(), "bnbbtc@trade");
+    }
+
+    #[test]
+    fn stream_key_aggregated_trade() {
+        let event: AggrTradesEvent = serde_json::from_str(
+            r#"{"e":"aggTrade","E":123456789,"s":"BNBBTC","a":12345,"p":"0.001","q":"100",
+            "f":100,"l":105,"T":123456785,"m":true,"M":true}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            WebsocketEvent::AggrTrades(event).stream_key().unwrap(),
+            "bnbbtc@aggTrade"
+        );
+    }
+
+    #[test]
+    fn stream_key_day_ticker() {
+        let event: DayTickerEvent = serde_json::from_str(
+            r#"{"e":"24hrTicker","E":123456789,"s":"BNBBTC","p":"0.0015","P":"250.00",
+            "w":"0.0018","x":"0.0009","c":"0.0025","Q":"10","b":"0.0024","B":"10","a":"0.0026",
+            "A":"100","o":"0.0010","h":"0.0025","l":"0.0010","v":"10000","q":"18","O":0,
+            "C":86400000,"F":0,"L":18150,"n":18151}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            WebsocketEvent::DayTicker(event.clone())
+                .stream_key()
+                .unwrap(),
+            "bnbbtc@ticker"
+        );
+        assert_eq!(
+            WebsocketEvent::DayTickerAll(vec![event])
+                .stream_key()
+                .unwrap(),
+            "!ticker@arr"
+        );
+    }
+
+    #[test]
+    fn stream_key_kline() {
+        let event: KlineEvent = serde_json::from_str(
+            r#"{"e":"kline","E":123456789,"s":"BNBBTC","k":{"t":123400000,"T":123460000,
+            "s":"BNBBTC","i":"1m","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025",
+            "l":"0.0015","v":"1000","n":100,"x":false,"q":"1.0000","V":"500","Q":"0.500",
+            "B":"123456"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            WebsocketEvent::Kline(event).stream_key().unwrap(),
+            "bnbbtc@kline_1m"
+        );
+    }
+
+    #[test]
+    fn stream_key_depth() {
+        let event: DepthOrderBookEvent = serde_json::from_str(
+            r#"{"e":"depthUpdate","E":123456789,"s":"BNBBTC","U":157,"u":160,
+            "b":[["0.0024","10"]],"a":[["0.0026","100"]]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            WebsocketEvent::DepthOrderBook(event).stream_key().unwrap(),
+            "bnbbtc@depth"
+        );
+    }
+
+    #[test]
+    fn stream_key_book_ticker() {
+        assert_eq!(book_ticker().stream_key().unwrap(), "bnbusdt@bookTicker");
+    }
+
+    #[test]
+    fn stream_key_without_market_stream() {
+        let event = BalanceUpdateEvent {
+            balance: vec![],
+            event_type: "balanceUpdate".into(),
+            event_time: 1_573_200_697_110,
+            last_account_update_time: 1_573_200_697_068,
+        };
+
+        assert!(WebsocketEvent::BalanceUpdate(event).stream_key().is_none());
+        assert!(WebsocketEvent::OrderBook(binance::spot::model::OrderBook {
+            last_update_id: 160,
+            bids: vec![],
+            asks: vec![],
+        })
+        .stream_key()
+        .is_none());
+    }
+
+    #[test]
+    fn stream_names() {
+        assert_eq!(
+            Stream::kline("BTCUSDT", Interval::OneMinute).as_str(),
+            "btcusdt@kline_1m"
+        );
+        assert_eq!(
+            Stream::kline("BTCUSDT", Interval::OneMonth).as_str(),
+            "btcusdt@kline_1M"
+        );
+        assert_eq!(
+            Stream::depth("BTCUSDT", DepthLevel::L20, UpdateSpeed::Ms100).as_str(),
+            "btcusdt@depth20@100ms"
+        );
+        assert_eq!(
+            Stream::depth("BTCUSDT", DepthLevel::L5, UpdateSpeed::Ms1000).as_str(),
+            "btcusdt@depth5"
+        );
+        assert_eq!(
+            Stream::diff_depth("BNBBTC", UpdateSpeed::Ms100).as_str(),
+            "bnbbtc@depth@100ms"
+        );
+        assert_eq!(Stream::agg_trade("BNBBTC").as_str(), "bnbbtc@aggTrade");
+        assert_eq!(Stream::all_tickers().as_str(), "!ticker@arr");
+        assert_eq!(
+            String::from(Stream::book_ticker("BNBBTC")),
+            "bnbbtc@bookTicker"
+        );
+    }
+
+    #[tokio::test]
+    async fn partial_book_depth() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let payload = r#"{"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"],["0.0027","50"]]}"#;
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@depth10@100ms", &config)
+            .await
+            .unwrap();
+
+        let order_book = match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::OrderBook(order_book)) => order_book,
+            event => panic!("unexpected event {:?}", event),
+        };
+
+        assert_eq!(order_book.last_update_id, 160);
+        assert_eq!(order_book.bids.len(), 1);
+        assert_eq!(order_book.asks.len(), 2);
+        assert_eq!(order_book.asks[1].price, Decimal::new(27, 4));
+    }
+
+    #[tokio::test]
+    async fn unknown_event() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let payload = r#"{"stream":"btcusdt@avgPrice","data":{"e":"avgPrice","E":1693907033000,"s":"BTCUSDT","i":"5m","w":"25776.86000000","T":1693907032213}}"#;
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("btcusdt@avgPrice", &config)
+            .await
+            .unwrap();
+
+        let event = web_socket.recv().await.unwrap().unwrap();
+        assert_eq!(event.event_time(), Some(1_693_907_033_000));
+        assert!(event.stream_key().is_none());
+        match event {
+            WebsocketEvent::Unknown(value) => {
+                assert_eq!(value["e"], "avgPrice");
+                assert_eq!(value["w"], "25776.86000000");
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_array_event() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let payload = r#"[{"e":"24hrMiniTicker","E":123456789,"s":"BNBBTC","c":"0.0025","o":"0.0010","h":"0.0025","l":"0.0010","v":"10000","q":"18"}]"#;
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket =
+            WebSockets::connect_with_config(Stream::all_mini_tickers().as_ref(), &config)
+                .await
+                .unwrap();
+
+        match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::Unknown(value)) => {
+                assert_eq!(value[0]["e"], "24hrMiniTicker");
+                assert_eq!(value[0]["c"], "0.0025");
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn recv_with_stream() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            for payload in [
+                r#"{"stream":"bnbbtc@depth5","data":{"lastUpdateId":160,"bids":[],"asks":[]}}"#,
+                r#"{"lastUpdateId":161,"bids":[],"asks":[]}"#,
+            ] {
+                websocket.send(Message::Text(payload.into())).await.unwrap();
+            }
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@depth5", &config)
+            .await
+            .unwrap();
+
+        match web_socket.recv_with_stream().await.unwrap() {
+            Some((Some(stream), WebsocketEvent::OrderBook(order_book))) => {
+                assert_eq!(stream, "bnbbtc@depth5");
+                assert_eq!(order_book.last_update_id, 160);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        match web_socket.recv_with_stream().await.unwrap() {
+            Some((None, WebsocketEvent::OrderBook(order_book))) => {
+                assert_eq!(order_book.last_update_id, 161);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn close_with() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let close = websocket.next().await.unwrap().unwrap();
+            // Flush the close acknowledgment.
+            assert!(websocket.next().await.is_none());
+            close
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@depth5", &config)
+            .await
+            .unwrap();
+
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            web_socket.close_with(CloseCode::Away, "shutting down"),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        match server.await.unwrap() {
+            Message::Close(Some(frame)) => {
+                assert_eq!(frame.code, CloseCode::Away);
+                assert_eq!(frame.reason, "shutting down");
+            }
+            message => panic!("unexpected message {:?}", message),
+        }
+    }
+
+    #[test]
+    fn retry_delay() {
+        let retry = RetryConfig {
+            base: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            jitter: 0.0,
+            max_attempts: None,
+        };
+
+        assert_eq!(retry.delay(1), Duration::from_millis(100));
+        assert_eq!(retry.delay(3), Duration::from_millis(400));
+        assert_eq!(retry.delay(5), Duration::from_secs(1));
+        assert_eq!(retry.delay(100), Duration::from_secs(1));
+
+        let retry = RetryConfig {
+            jitter: 0.5,
+            ..retry
+        };
+        let delay = retry.delay(2);
+        assert!(delay >= Duration::from_millis(200));
+        assert!(delay <= Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn reconnect_after_close() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            for last_update_id in [160, 170] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let payload = format!(r#"{{"lastUpdateId":{last_update_id},"bids":[],"asks":[]}}"#);
+                websocket.send(Message::Text(payload)).await.unwrap();
+                websocket.close(None).await.unwrap();
+            }
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let retry = RetryConfig {
+            base: Duration::from_millis(10),
+            max_attempts: Some(3),
+            ..RetryConfig::default()
+        };
+        let mut web_socket =
+            WebSockets::connect_with_reconnect_config("bnbbtc@depth5", &config, retry)
+                .await
+                .unwrap();
+
+        let mut events = vec![];
+        while events.len() < 3 {
+            if let Some(event) = web_socket.recv().await.unwrap() {
+                events.push(event);
+            }
+        }
+
+        match &events[..] {
+            [WebsocketEvent::OrderBook(first), WebsocketEvent::Reconnected(reconnect), WebsocketEvent::OrderBook(second)] =>
+            {
+                assert_eq!(first.last_update_id, 160);
+                assert_eq!(reconnect.attempts, 1);
+                assert_eq!(second.last_update_id, 170);
+            }
+            events => panic!("unexpected events {:?}", events),
+        }
+    }
+
+    #[tokio::test]
+    async fn no_reconnect_after_disconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            for last_update_id in [160, 170] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let payload = format!(r#"{{"lastUpdateId":{last_update_id},"bids":[],"asks":[]}}"#);
+                websocket.send(Message::Text(payload)).await.unwrap();
+                while let Some(Ok(message)) = websocket.next().await {
+                    if message.is_close() {
+                        break;
+                    }
+                }
+            }
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let retry = RetryConfig {
+            base: Duration::from_millis(10),
+            max_attempts: Some(3),
+            ..RetryConfig::default()
+        };
+        let mut web_socket =
+            WebSockets::connect_with_reconnect_config("bnbbtc@depth5", &config, retry)
+                .await
+                .unwrap();
+
+        match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::OrderBook(order_book)) => {
+                assert_eq!(order_book.last_update_id, 160);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        web_socket.disconnect().await.unwrap();
+
+        loop {
+            match web_socket.recv().await {
+                Ok(None) => continue,
+                Ok(Some(event)) => panic!("unexpected event {:?}", event),
+                Err(_) => break,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribe_and_list_subscriptions() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let mut requests = vec![];
+            for response in [
+                r#"{"result":null,"id":1}"#,
+                r#"{"result":["bnbbtc@trade"],"id":2}"#,
+                r#"{"error":{"code":2,"msg":"Invalid request"},"id":3}"#,
+            ] {
+                let request = websocket
+                    .next()
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .into_text()
+                    .unwrap();
+                requests.push(serde_json::from_str::<serde_json::Value>(&request).unwrap());
+                websocket
+                    .send(Message::Text(response.into()))
+                    .await
+                    .unwrap();
+            }
+            requests
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@depth5", &config)
+            .await
+            .unwrap();
+
+        let id = web_socket
+            .subscribe(&["bnbbtc@trade".into()])
+            .await
+            .unwrap();
+        assert_eq!(id, 1);
+        match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::Response(response)) => {
+                assert_eq!(
+                    response,
+                    ControlResponse {
+                        id: 1,
+                        result: None
+                    }
+                );
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        let id = web_socket.list_subscriptions().await.unwrap();
+        match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::Response(response)) => {
+                assert_eq!(response.id, id);
+                assert_eq!(response.result, Some(vec!["bnbbtc@trade".into()]));
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        web_socket
+            .unsubscribe(&["bnbusdt@trade".into()])
+            .await
+            .unwrap();
+        assert!(web_socket.recv().await.is_err());
+
+        let requests = server.await.unwrap();
+        assert_eq!(
+            requests[0],
+            serde_json::json!({"method": "SUBSCRIBE", "params": ["bnbbtc@trade"], "id": 1})
+        );
+        assert_eq!(
+            requests[1],
+            serde_json::json!({"method": "LIST_SUBSCRIPTIONS", "id": 2})
+        );
+        assert_eq!(
+            requests[2],
+            serde_json::json!({"method": "UNSUBSCRIBE", "params": ["bnbusdt@trade"], "id": 3})
+        );
+    }
+
+    #[tokio::test]
+    async fn recv_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        let (done, stop) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            // Keep the connection open without sending anything.
+            let _websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _ = stop.await;
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@depth5", &config)
+            .await
+            .unwrap();
+
+        let error = web_socket
+            .recv_timeout(Duration::from_millis(50))
+            .await
+            .unwrap_err();
+        match error.kind() {
+            ErrorKind::WebsocketTimeout(timeout) => {
+                assert_eq!(*timeout, Duration::from_millis(50));
+            }
+            kind => panic!("{:?}", kind),
+        }
+        let _ = done.send(());
+    }
+
+    #[tokio::test]
+    async fn recv_ordered_releases_on_stalled_stream() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        let (done, stop) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let payload = r#"{"e":"trade","E":123456789,"s":"BNBBTC","t":12345,"p":"0.001","q":"100","b":88,"a":50,"T":123456785,"m":true,"M":true}"#;
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+            // Stall without sending anything else.
+            let _ = stop.await;
+        });
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@trade", &config)
+            .await
+            .unwrap();
+        let mut buffer = EventReorderBuffer::new(Duration::from_millis(50));
+
+        let event = tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                if let Some(event) = web_socket.recv_ordered(&mut buffer).await.unwrap() {
+                    return event;
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(trade_id(&event), 12345);
+        assert!(buffer.is_empty());
+        let _ = done.send(());
+    }
+
+    #[tokio::test]
+    async fn user_stream_keep_alive_and_restart() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let expired = r#"{"e":"listenKeyExpired","E":1576653824250,"listenKey":"key1"}"#;
+            websocket.send(Message::Text(expired.into())).await.unwrap();
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            // Stay silent long enough for the listen key to be kept alive.
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            let expired = r#"{"e":"listenKeyExpired","E":1576653824251,"listenKey":"key2"}"#;
+            websocket.send(Message::Text(expired.into())).await.unwrap();
+
+            // Keep the restarted connection open.
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _ = websocket.next().await;
+        });
+        let mut server = mockito::Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"key"}"#)
+            .expect(3)
+            .create();
+        let mock_keep_alive = server
+            .mock("PUT", "/api/v3/userDataStream")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .expect_at_least(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream = UserStream::new_with_config(None, None, &config).unwrap();
+        let mut web_socket = UserStreamWebSocket::connect(user_stream, &config)
+            .await
+            .unwrap()
+            .with_keep_alive_interval(Duration::from_millis(50));
+        assert_eq!(web_socket.listen_key(), "key");
+
+        match web_socket.recv().await.unwrap() {
+            Some(WebsocketEvent::ListenKeyExpired(event)) => {
+                assert_eq!(event.event_time, 1_576_653_824_250);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+        let mut keep_alives = 0;
+        loop {
+            match web_socket.recv().await.unwrap() {
+                None => keep_alives += 1,
+                Some(WebsocketEvent::ListenKeyExpired(event)) => {
+                    assert_eq!(event.event_time, 1_576_653_824_251);
+                    break;
+                }
+                event => panic!("unexpected event {:?}", event),
+            }
+        }
+        assert!(keep_alives >= 1);
+
+        mock_start.assert();
+        mock_keep_alive.assert();
+    }
+
+    #[tokio::test]
+    async fn user_stream_restart_after_close() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            websocket.close(None).await.unwrap();
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let payload = r#"{"lastUpdateId":160,"bids":[],"asks":[]}"#;
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+            let _ = websocket.next().await;
+        });
+        let mut server = mockito::Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"key"}"#)
+            .expect(2)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream = UserStream::new_with_config(None, None, &config).unwrap();
+        let mut web_socket = UserStreamWebSocket::connect(user_stream, &config)
+            .await
+            .unwrap();
+
+        let mut events = vec![];
+        while events.len() < 2 {
+            if let Some(event) = web_socket.recv().await.unwrap() {
+                events.push(event);
+            }
+        }
+        match &events[..] {
+            [WebsocketEvent::Reconnected(reconnect), WebsocketEvent::OrderBook(order_book)] => {
+                assert_eq!(reconnect.attempts, 1);
+                assert_eq!(order_book.last_update_id, 160);
+            }
+            events => panic!("unexpected events {:?}", events),
+        }
+
+        mock_start.assert();
+    }
+
+    #[tokio::test]
+    async fn user_stream_returns_control_errors() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let payload = r#"{"error":{"code":2,"msg":"Invalid request"},"id":1}"#;
+            websocket.send(Message::Text(payload.into())).await.unwrap();
+            let _ = websocket.next().await;
+        });
+        let mut server = mockito::Server::new_async().await;
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"key"}"#)
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(ws_endpoint);
+        let user_stream = UserStream::new_with_config(None, None, &config).unwrap();
+        let mut web_socket = UserStreamWebSocket::connect(user_stream, &config)
+            .await
+            .unwrap();
+
+        let err = web_socket.recv().await.unwrap_err();
+        match err.kind() {
+            ErrorKind::BinanceError(response) => assert_eq!(response.code, 2),
+            kind => panic!("unexpected error {:?}", kind),
+        }
+
+        mock_start.assert();
+    }
+
+    #[test]
+    fn quote_from_book_ticker() {
+        let event = match book_ticker() {
+            WebsocketEvent::BookTicker(event) => event,
+            _ => panic!(),
+        };
+        let quote = Quote::try_from(&event).unwrap();
+
+        assert_eq!(quote.symbol, "BNBUSDT");
+        assert_eq!(quote.bid, Decimal::new(2_535_190_000, 8));
+        assert_eq!(quote.bid_qty, Decimal::new(3_121_000_000, 8));
+        assert_eq!(quote.ask, Decimal::new(2_536_520_000, 8));
+        assert_eq!(quote.ask_qty, Decimal::new(4_066_000_000, 8));
+        assert_eq!(quote.update_id, 400_900_217);
+    }
+
+    #[test]
+    fn quote_from_invalid_book_ticker() {
+        let event = BookTickerEvent {
+            update_id: 1,
+            symbol: "BNBUSDT".into(),
+            best_bid: "not a price".into(),
+            best_bid_qty: "1".into(),
+            best_ask: "25.3652".into(),
+            best_ask_qty: "1".into(),
+        };
+
+        assert!(Quote::try_from(&event).is_err());
+    }
+}